@@ -0,0 +1,52 @@
+use minijinja::Environment;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct BuildScriptContext {
+    /// The directory that contains the `configure` script or `configure.ac`.
+    pub source_dir: String,
+    /// Regenerate the `configure` script with `autoreconf -fi` before
+    /// configuring, this is the case if the project contains a
+    /// `configure.ac`.
+    pub autoreconf: bool,
+    pub extra_configure_args: Vec<String>,
+    pub extra_make_args: Vec<String>,
+}
+
+impl BuildScriptContext {
+    pub fn render(&self) -> String {
+        let env = Environment::new();
+        let template = env
+            .template_from_str(include_str!("build_script.j2"))
+            .unwrap();
+        template.render(self).unwrap().trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::*;
+
+    use super::*;
+
+    #[rstest]
+    fn test_build_script(#[values(true, false)] autoreconf: bool) {
+        let context = BuildScriptContext {
+            source_dir: String::from("my-prefix-dir"),
+            autoreconf,
+            extra_configure_args: vec![String::from("--disable-static")],
+            extra_make_args: vec![String::from("V=1")],
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(if autoreconf {
+            "autoreconf"
+        } else {
+            "configure"
+        });
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+}
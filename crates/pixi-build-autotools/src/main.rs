@@ -0,0 +1,312 @@
+mod build_script;
+mod config;
+
+use build_script::BuildScriptContext;
+use config::AutotoolsBackendConfig;
+use miette::IntoDiagnostic;
+use pixi_build_backend::{
+    generated_recipe::{DefaultMetadataProvider, GenerateRecipe, GeneratedRecipe, PythonParams},
+    intermediate_backend::IntermediateBackendInstantiator,
+    traits::ProjectModel,
+};
+use pixi_build_types::{ProjectModelV1, SourcePackageName};
+use rattler_build::{NormalizedKey, recipe::variable::Variable};
+use rattler_conda_types::{ChannelUrl, Platform};
+use recipe_stage0::recipe::Script;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    sync::Arc,
+};
+
+#[derive(Default, Clone)]
+pub struct AutotoolsGenerator {}
+
+impl GenerateRecipe for AutotoolsGenerator {
+    type Config = AutotoolsBackendConfig;
+
+    fn generate_recipe(
+        &self,
+        model: &ProjectModelV1,
+        config: &Self::Config,
+        manifest_path: PathBuf,
+        host_platform: Platform,
+        _python_params: Option<PythonParams>,
+        variants: &HashSet<NormalizedKey>,
+        _channels: Vec<ChannelUrl>,
+    ) -> miette::Result<GeneratedRecipe> {
+        if Platform::current().is_windows() {
+            miette::bail!("pixi-build-autotools does not support building on Windows");
+        }
+
+        // Determine the manifest root, because `manifest_path` can be
+        // either a direct file path or a directory path.
+        let manifest_root = if manifest_path.is_file() {
+            manifest_path
+                .parent()
+                .ok_or_else(|| {
+                    miette::Error::msg(format!(
+                        "Manifest path {} is a file but has no parent directory.",
+                        manifest_path.display()
+                    ))
+                })?
+                .to_path_buf()
+        } else {
+            manifest_path.clone()
+        };
+
+        // A `configure.ac` is turned into a `configure` script first, a
+        // shipped `configure` script is used as is.
+        let autoreconf = manifest_root.join("configure.ac").is_file();
+        if !autoreconf && !manifest_root.join("configure").is_file() {
+            miette::bail!(
+                "no `configure.ac` or `configure` script found in {}",
+                manifest_root.display()
+            );
+        }
+
+        let mut generated_recipe =
+            GeneratedRecipe::from_model(model.clone(), &mut DefaultMetadataProvider)
+                .into_diagnostic()?;
+
+        let requirements = &mut generated_recipe.recipe.requirements;
+
+        let model_dependencies = model.dependencies(Some(host_platform));
+
+        let compilers = config
+            .compilers
+            .clone()
+            .unwrap_or_else(|| vec!["c".to_string()]);
+        pixi_build_backend::compilers::add_compilers_to_requirements(
+            &compilers,
+            &mut requirements.build,
+            &model_dependencies,
+            &host_platform,
+        );
+        pixi_build_backend::compilers::add_stdlib_to_requirements(
+            &compilers,
+            &mut requirements.build,
+            variants,
+        );
+
+        // Regenerating the `configure` script requires the autotools
+        // themselves, building always requires `make`.
+        let build_tools: &[&str] = if autoreconf {
+            &["autoconf", "automake", "libtool", "make"]
+        } else {
+            &["make"]
+        };
+        for tool in build_tools {
+            if !model_dependencies
+                .build
+                .contains_key(&SourcePackageName::from(*tool))
+            {
+                requirements.build.push(tool.parse().into_diagnostic()?);
+            }
+        }
+
+        let build_script = BuildScriptContext {
+            source_dir: manifest_root.display().to_string(),
+            autoreconf,
+            extra_configure_args: config.extra_configure_args.clone(),
+            extra_make_args: config.extra_make_args.clone(),
+        }
+        .render();
+
+        generated_recipe.recipe.build.script = Script {
+            content: build_script,
+            env: config.env.clone(),
+            ..Default::default()
+        };
+
+        Ok(generated_recipe)
+    }
+
+    fn extract_input_globs_from_build(
+        &self,
+        config: &Self::Config,
+        _manifest_root: impl AsRef<Path>,
+        _editable: bool,
+    ) -> miette::Result<BTreeSet<String>> {
+        Ok([
+            "configure.ac",
+            "configure",
+            "**/Makefile.am",
+            "**/Makefile.in",
+            "**/*.c",
+            "**/*.h",
+        ]
+        .iter()
+        .map(|s: &&str| s.to_string())
+        .chain(config.extra_input_globs.clone())
+        .chain(
+            config
+                .exclude_input_globs
+                .iter()
+                .map(|glob| format!("!{glob}")),
+        )
+        .collect())
+    }
+
+    fn default_variants(
+        &self,
+        _host_platform: Platform,
+    ) -> miette::Result<BTreeMap<NormalizedKey, Vec<Variable>>> {
+        Ok(BTreeMap::new())
+    }
+}
+
+#[tokio::main]
+pub async fn main() {
+    if let Err(err) = pixi_build_backend::cli::main(|log| {
+        IntermediateBackendInstantiator::<AutotoolsGenerator>::new(log, Arc::default())
+    })
+    .await
+    {
+        eprintln!("{err:?}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    #[macro_export]
+    macro_rules! project_fixture {
+        ($($json:tt)+) => {
+            serde_json::from_value::<ProjectModelV1>(
+                serde_json::json!($($json)+)
+            ).expect("Failed to create TestProjectModel from JSON fixture.")
+        };
+    }
+
+    fn build_requirements(generated_recipe: &GeneratedRecipe) -> Vec<String> {
+        generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect()
+    }
+
+    fn generate(
+        manifest_root: &Path,
+        config: &AutotoolsBackendConfig,
+    ) -> miette::Result<GeneratedRecipe> {
+        let project_model = project_fixture!({
+            "name": "libfoo",
+            "version": "0.1.0",
+        });
+
+        AutotoolsGenerator::default().generate_recipe(
+            &project_model,
+            config,
+            manifest_root.to_path_buf(),
+            Platform::Linux64,
+            None,
+            &HashSet::new(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_input_globs_includes_extra_globs() {
+        let config = AutotoolsBackendConfig {
+            extra_input_globs: vec!["data/*.txt".to_string()],
+            ..Default::default()
+        };
+
+        let generator = AutotoolsGenerator::default();
+
+        let result = generator.extract_input_globs_from_build(&config, PathBuf::new(), false);
+
+        insta::assert_debug_snapshot!(result);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_configure_ac_runs_autoreconf() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("configure.ac"),
+            "AC_INIT([libfoo], [0.1.0])\n",
+        )
+        .unwrap();
+
+        let generated_recipe =
+            generate(temp_dir.path(), &AutotoolsBackendConfig::default()).unwrap();
+
+        assert_eq!(
+            build_requirements(&generated_recipe),
+            vec![
+                "${{ compiler('c') }}",
+                "autoconf",
+                "automake",
+                "libtool",
+                "make"
+            ]
+        );
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("autoreconf -fi")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_configure_script_is_used_as_is() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("configure"), "#!/bin/sh\n").unwrap();
+
+        let env = IndexMap::from([("CFLAGS".to_string(), "-O3".to_string())]);
+        let generated_recipe = generate(
+            temp_dir.path(),
+            &AutotoolsBackendConfig {
+                env: env.clone(),
+                extra_configure_args: vec!["--disable-static".to_string()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            build_requirements(&generated_recipe),
+            vec!["${{ compiler('c') }}", "make"]
+        );
+        let script = &generated_recipe.recipe.build.script;
+        assert_eq!(script.env, env);
+        assert!(!script.content.contains("autoreconf"));
+        assert!(
+            script
+                .content
+                .contains("./configure --prefix=\"$PREFIX\" --disable-static")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_missing_configure_is_an_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let Err(error) = generate(temp_dir.path(), &AutotoolsBackendConfig::default()) else {
+            panic!("generating a recipe without a configure script should fail");
+        };
+        assert!(
+            error
+                .to_string()
+                .contains("no `configure.ac` or `configure` script found")
+        );
+    }
+}
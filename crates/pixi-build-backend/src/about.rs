@@ -0,0 +1,87 @@
+use pixi_build_types::ProjectModelV1;
+use recipe_stage0::recipe::{About, Value};
+
+/// Constructs the [`About`] section of a recipe from the metadata in the
+/// project model.
+///
+/// Fields that are not specified in the project model are left empty. The
+/// project model has no notion of a separate summary, and `authors` has no
+/// equivalent in the `about` section, so these are not mapped.
+pub fn about_from_project_model(model: &ProjectModelV1) -> About {
+    About {
+        homepage: model
+            .homepage
+            .as_ref()
+            .map(|url| Value::Concrete(url.to_string())),
+        license: model.license.clone().map(Value::Concrete),
+        license_file: model
+            .license_file
+            .as_ref()
+            .map(|path| Value::Concrete(path.display().to_string())),
+        summary: None,
+        description: model.description.clone().map(Value::Concrete),
+        documentation: model
+            .documentation
+            .as_ref()
+            .map(|url| Value::Concrete(url.to_string())),
+        repository: model
+            .repository
+            .as_ref()
+            .map(|url| Value::Concrete(url.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::PathBuf, str::FromStr};
+
+    use rattler_conda_types::Version;
+
+    use super::*;
+
+    #[test]
+    fn test_about_from_fully_specified_model() {
+        let model = ProjectModelV1 {
+            name: Some("foobar".to_string()),
+            version: Some(Version::from_str("0.1.0").unwrap()),
+            description: Some("A fully specified package".to_string()),
+            authors: Some(vec!["John Doe <john@example.com>".to_string()]),
+            license: Some("MIT".to_string()),
+            license_file: Some(PathBuf::from("LICENSE")),
+            readme: Some(PathBuf::from("README.md")),
+            homepage: Some("https://example.com".parse().unwrap()),
+            repository: Some("https://github.com/example/foobar".parse().unwrap()),
+            documentation: Some("https://docs.example.com".parse().unwrap()),
+            targets: None,
+        };
+
+        let about = about_from_project_model(&model);
+        insta::assert_yaml_snapshot!(about);
+    }
+
+    #[test]
+    fn test_about_from_empty_model() {
+        let model = ProjectModelV1 {
+            name: Some("foobar".to_string()),
+            version: None,
+            description: None,
+            authors: None,
+            license: None,
+            license_file: None,
+            readme: None,
+            homepage: None,
+            repository: None,
+            documentation: None,
+            targets: None,
+        };
+
+        let about = about_from_project_model(&model);
+        assert!(about.homepage.is_none());
+        assert!(about.license.is_none());
+        assert!(about.license_file.is_none());
+        assert!(about.summary.is_none());
+        assert!(about.description.is_none());
+        assert!(about.documentation.is_none());
+        assert!(about.repository.is_none());
+    }
+}
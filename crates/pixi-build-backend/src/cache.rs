@@ -8,6 +8,10 @@ pub fn sccache_tools() -> Vec<String> {
     vec!["sccache".to_string()]
 }
 
+pub fn ccache_tools() -> Vec<String> {
+    vec!["ccache".to_string()]
+}
+
 /// Return environment variables that are used by sccache.
 pub fn sccache_envs(env: &HashMap<String, String>) -> Option<Vec<&str>> {
     let res = env
@@ -18,6 +22,16 @@ pub fn sccache_envs(env: &HashMap<String, String>) -> Option<Vec<&str>> {
     if res.is_empty() { None } else { Some(res) }
 }
 
+/// Return environment variables that are used by ccache.
+pub fn ccache_envs(env: &HashMap<String, String>) -> Option<Vec<&str>> {
+    let res = env
+        .keys()
+        .filter(|k| k.starts_with("CCACHE"))
+        .map(|k| k.as_str())
+        .collect_vec();
+    if res.is_empty() { None } else { Some(res) }
+}
+
 pub fn add_sccache<'a, P: ProjectModel>(
     dependencies: &mut Dependencies<'a, <P::Targets as Targets>::Spec>,
     sccache_tools: &'a [String],
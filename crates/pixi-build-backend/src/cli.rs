@@ -1,14 +1,22 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use clap_verbosity_flag::{InfoLevel, Verbosity};
 use miette::IntoDiagnostic;
 use pixi_build_types::{
     BackendCapabilities, FrontendCapabilities,
-    procedures::negotiate_capabilities::NegotiateCapabilitiesParams,
+    procedures::{
+        initialize::InitializeParams, negotiate_capabilities::NegotiateCapabilitiesParams,
+    },
 };
 use rattler_build::console_utils::{LoggingOutputHandler, get_default_env_filter};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-use crate::{protocol::ProtocolInstantiator, server::Server};
+use crate::{
+    lint::{CondaLintParams, LintSeverity},
+    protocol::ProtocolInstantiator,
+    server::Server,
+};
 
 #[allow(missing_docs)]
 #[derive(Parser)]
@@ -17,11 +25,16 @@ pub struct App {
     #[clap(subcommand)]
     command: Option<Commands>,
 
-    /// The port to expose the json-rpc server on. If not specified will
-    /// communicate with stdin/stdout.
-    #[clap(long)]
+    /// The port to expose the json-rpc server on. If neither this nor
+    /// `--unix-socket` is specified will communicate with stdin/stdout.
+    #[clap(long, conflicts_with = "unix_socket")]
     http_port: Option<u16>,
 
+    /// The path of the UNIX domain socket to expose the json-rpc server on.
+    /// Not supported on Windows.
+    #[clap(long)]
+    unix_socket: Option<PathBuf>,
+
     /// Enable verbose logging.
     #[command(flatten)]
     verbose: Verbosity<InfoLevel>,
@@ -30,14 +43,36 @@ pub struct App {
 #[derive(Subcommand, Debug)]
 pub enum Commands {
     /// Get the capabilities of the backend.
-    Capabilities,
+    Capabilities {
+        /// Print the capabilities as a JSON object to stdout.
+        #[clap(long)]
+        json: bool,
+
+        /// Fail if any of the capabilities is not set.
+        #[clap(long)]
+        check: bool,
+    },
+    /// Check a package for problems without building it. Backends that
+    /// generate their recipe from the project model need it to be passed by
+    /// pixi and can only be linted through `conda/lint`.
+    Lint {
+        /// The manifest of the package to lint.
+        manifest_path: PathBuf,
+    },
 }
 
-/// Run the sever on the specified port or over stdin/stdout.
-async fn run_server<T: ProtocolInstantiator>(port: Option<u16>, protocol: T) -> miette::Result<()> {
+/// Run the sever on the specified port, on the specified UNIX domain socket or
+/// over stdin/stdout.
+async fn run_server<T: ProtocolInstantiator>(
+    port: Option<u16>,
+    unix_socket: Option<PathBuf>,
+    protocol: T,
+) -> miette::Result<()> {
     let server = Server::new(protocol);
     if let Some(port) = port {
         server.run_over_http(port)
+    } else if let Some(unix_socket) = unix_socket {
+        server.run_over_unix_socket(&unix_socket).await
     } else {
         // running over stdin/stdout
         server.run().await
@@ -60,25 +95,42 @@ pub(crate) async fn main_impl<T: ProtocolInstantiator, F: FnOnce(LoggingOutputHa
     let factory = factory(log_handler);
 
     match args.command {
-        None => run_server(args.http_port, factory).await,
-        Some(Commands::Capabilities) => {
+        None => run_server(args.http_port, args.unix_socket, factory).await,
+        Some(Commands::Capabilities { json, check }) => {
             let backend_capabilities = capabilities::<T>().await?;
-            eprintln!(
-                "Supports {}: {}",
-                pixi_build_types::procedures::conda_outputs::METHOD_NAME,
-                backend_capabilities.provides_conda_outputs()
-            );
-            eprintln!(
-                "Supports {}: {}",
-                pixi_build_types::procedures::conda_build_v1::METHOD_NAME,
-                backend_capabilities.provides_conda_build_v1()
-            );
-            eprintln!(
-                "Highest project model: {}",
-                backend_capabilities.highest_supported_project_model()
-            );
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&backend_capabilities).into_diagnostic()?
+                );
+            } else {
+                eprintln!(
+                    "Supports {}: {}",
+                    pixi_build_types::procedures::conda_outputs::METHOD_NAME,
+                    backend_capabilities.provides_conda_outputs()
+                );
+                eprintln!(
+                    "Supports {}: {}",
+                    pixi_build_types::procedures::conda_build_v1::METHOD_NAME,
+                    backend_capabilities.provides_conda_build_v1()
+                );
+                eprintln!(
+                    "Highest project model: {}",
+                    backend_capabilities.highest_supported_project_model()
+                );
+            }
+            if check {
+                let missing = missing_capabilities(&backend_capabilities);
+                if !missing.is_empty() {
+                    miette::bail!(
+                        "the backend does not set the capabilities: {}",
+                        missing.join(", ")
+                    );
+                }
+            }
             Ok(())
         }
+        Some(Commands::Lint { manifest_path }) => lint(factory, manifest_path).await,
     }
 }
 
@@ -99,6 +151,68 @@ pub async fn main_ext<T: ProtocolInstantiator, F: FnOnce(LoggingOutputHandler) -
     main_impl(factory, args).await
 }
 
+/// The entry point for the CLI of a backend with its own subcommands, which
+/// are listed in `--help` next to the shared ones. A backend subcommand is
+/// passed to `run_subcommand`, everything else is handled like [`main`].
+pub async fn main_with_subcommands<
+    S: Subcommand,
+    T: ProtocolInstantiator,
+    F: FnOnce(LoggingOutputHandler) -> T,
+>(
+    factory: F,
+    run_subcommand: impl FnOnce(S) -> miette::Result<()>,
+) -> miette::Result<()> {
+    let matches = S::augment_subcommands(App::command()).get_matches();
+    if matches.subcommand_name().is_some_and(S::has_subcommand) {
+        let subcommand = S::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+        return run_subcommand(subcommand);
+    }
+
+    let args = App::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    main_impl(factory, args).await
+}
+
+/// Lints the package at `manifest_path` and prints the problems that were
+/// found. Fails if any of them is an error.
+async fn lint<T: ProtocolInstantiator>(factory: T, manifest_path: PathBuf) -> miette::Result<()> {
+    let (protocol, _) = factory
+        .initialize(InitializeParams {
+            workspace_root: None,
+            source_dir: None,
+            manifest_path: manifest_path.clone(),
+            project_model: None,
+            configuration: None,
+            target_configuration: None,
+            cache_directory: None,
+        })
+        .await?;
+    let result = protocol
+        .conda_lint(CondaLintParams {
+            manifest_path,
+            lint_config: None,
+        })
+        .await?;
+
+    for diagnostic in &result.diagnostics {
+        let location = match (&diagnostic.file, diagnostic.line) {
+            (Some(file), Some(line)) => format!("{}:{line}: ", file.display()),
+            (Some(file), None) => format!("{}: ", file.display()),
+            (None, _) => String::new(),
+        };
+        eprintln!("{location}{}: {}", diagnostic.severity, diagnostic.message);
+    }
+
+    let errors = result
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == LintSeverity::Error)
+        .count();
+    if errors > 0 {
+        miette::bail!("found {errors} lint error(s)");
+    }
+    Ok(())
+}
+
 /// Returns the capabilities of the backend.
 async fn capabilities<Factory: ProtocolInstantiator>() -> miette::Result<BackendCapabilities> {
     let result = Factory::negotiate_capabilities(NegotiateCapabilitiesParams {
@@ -108,3 +222,78 @@ async fn capabilities<Factory: ProtocolInstantiator>() -> miette::Result<Backend
 
     Ok(result.capabilities)
 }
+
+/// Returns the names of the capabilities that are not set.
+fn missing_capabilities(capabilities: &BackendCapabilities) -> Vec<&'static str> {
+    [
+        (
+            "provides_conda_outputs",
+            capabilities.provides_conda_outputs.is_some(),
+        ),
+        (
+            "provides_conda_build_v1",
+            capabilities.provides_conda_build_v1.is_some(),
+        ),
+        (
+            "highest_supported_project_model",
+            capabilities.highest_supported_project_model.is_some(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, is_set)| !is_set)
+    .map(|(name, _)| name)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Subcommand, Debug, PartialEq)]
+    enum BackendCommands {
+        /// A subcommand that is specific to a backend.
+        Extra { path: PathBuf },
+    }
+
+    #[test]
+    fn test_backend_subcommands() {
+        let command = BackendCommands::augment_subcommands(App::command());
+        command.clone().debug_assert();
+
+        let matches = command
+            .clone()
+            .try_get_matches_from(["backend", "extra", "recipe.yaml"])
+            .unwrap();
+        assert_eq!(
+            BackendCommands::from_arg_matches(&matches).unwrap(),
+            BackendCommands::Extra {
+                path: PathBuf::from("recipe.yaml")
+            }
+        );
+
+        // The shared subcommands are still parsed by the app.
+        let matches = command
+            .try_get_matches_from(["backend", "lint", "pixi.toml"])
+            .unwrap();
+        assert!(!BackendCommands::has_subcommand(
+            matches.subcommand_name().unwrap()
+        ));
+        assert!(matches!(
+            App::from_arg_matches(&matches).unwrap().command,
+            Some(Commands::Lint { .. })
+        ));
+    }
+
+    #[test]
+    fn test_missing_capabilities() {
+        let capabilities = BackendCapabilities {
+            provides_conda_outputs: Some(true),
+            provides_conda_build_v1: None,
+            highest_supported_project_model: None,
+        };
+        assert_eq!(
+            missing_capabilities(&capabilities),
+            vec!["provides_conda_build_v1", "highest_supported_project_model"]
+        );
+    }
+}
@@ -13,7 +13,10 @@ use std::{
 };
 use thiserror::Error;
 
-use crate::specs_conversion::from_targets_v1_to_conditional_requirements;
+use crate::{
+    about::about_from_project_model, lint::LintDiagnostic,
+    specs_conversion::from_targets_v1_to_conditional_requirements,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct PythonParams {
@@ -68,10 +71,13 @@ pub trait GenerateRecipe {
     /// for the build process.
     /// For example, this could be a list of source files or configuration files
     /// used by Cmake.
+    ///
+    /// `manifest_root` is the directory of the project manifest that was also
+    /// passed to [`GenerateRecipe::generate_recipe`].
     fn extract_input_globs_from_build(
         &self,
         _config: &Self::Config,
-        _workdir: impl AsRef<Path>,
+        _manifest_root: impl AsRef<Path>,
         _editable: bool,
     ) -> miette::Result<BTreeSet<String>> {
         Ok(BTreeSet::new())
@@ -90,6 +96,19 @@ pub trait GenerateRecipe {
     ) -> miette::Result<BTreeMap<NormalizedKey, Vec<Variable>>> {
         Ok(BTreeMap::new())
     }
+
+    /// Checks the project in `manifest_root` for problems that are likely to
+    /// break the build, e.g. metadata that contradicts the project model.
+    ///
+    /// The default implementation finds no problems.
+    fn lint(
+        &self,
+        _model: &ProjectModelV1,
+        _config: &Self::Config,
+        _manifest_root: &Path,
+    ) -> miette::Result<Vec<LintDiagnostic>> {
+        Ok(Vec::new())
+    }
 }
 
 pub trait BackendConfig: DeserializeOwned + Clone {
@@ -126,6 +145,8 @@ impl GeneratedRecipe {
         model: ProjectModelV1,
         provider: &mut M,
     ) -> Result<Self, GenerateRecipeError<M::Error>> {
+        let model_about = about_from_project_model(&model);
+
         // If the name is not defined in the model, we try to get it from the provider.
         // If the provider cannot provide a name, we return an error.
         let name = match model.name {
@@ -163,28 +184,32 @@ impl GeneratedRecipe {
         let requirements =
             from_targets_v1_to_conditional_requirements(&model.targets.unwrap_or_default());
 
+        // Values that are not defined in the model are taken from the provider.
         macro_rules! derive_value {
             ($ident:ident) => {
-                match model.$ident {
-                    Some(v) => Some(v.to_string()),
-                    None => provider.$ident().map_err(|e| {
-                        GenerateRecipeError::MetadataProviderError(
-                            String::from(stringify!($ident)),
-                            e,
-                        )
-                    })?,
+                match model_about.$ident {
+                    Some(v) => Some(v),
+                    None => provider
+                        .$ident()
+                        .map_err(|e| {
+                            GenerateRecipeError::MetadataProviderError(
+                                String::from(stringify!($ident)),
+                                e,
+                            )
+                        })?
+                        .map(Value::Concrete),
                 }
             };
         }
 
         let about = About {
-            homepage: derive_value!(homepage).map(Value::Concrete),
-            license: derive_value!(license).map(Value::Concrete),
-            description: derive_value!(description).map(Value::Concrete),
-            documentation: derive_value!(documentation).map(Value::Concrete),
-            repository: derive_value!(repository).map(Value::Concrete),
-            license_file: match model.license_file {
-                Some(v) => Some(Value::Concrete(v.display().to_string())),
+            homepage: derive_value!(homepage),
+            license: derive_value!(license),
+            description: derive_value!(description),
+            documentation: derive_value!(documentation),
+            repository: derive_value!(repository),
+            license_file: match model_about.license_file {
+                Some(v) => Some(v),
                 None => provider
                     .license_files()
                     .map_err(|e| {
@@ -192,12 +217,7 @@ impl GeneratedRecipe {
                     })?
                     .map(|files| Value::Concrete(files.join(", "))),
             },
-            summary: provider
-                .summary()
-                .map_err(|e| {
-                    GenerateRecipeError::MetadataProviderError(String::from("summary"), e)
-                })?
-                .map(Value::Concrete),
+            summary: derive_value!(summary),
         };
 
         let ir = IntermediateRecipe {
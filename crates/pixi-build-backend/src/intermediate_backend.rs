@@ -45,6 +45,7 @@ use crate::{
         convert_binary_dependencies, convert_dependencies, convert_input_variant_configuration,
     },
     generated_recipe::{BackendConfig, GenerateRecipe, PythonParams},
+    lint::{CondaLintParams, CondaLintResult},
     protocol::{Protocol, ProtocolInstantiator},
     specs_conversion::{
         convert_variant_from_pixi_build_types, convert_variant_to_pixi_build_types,
@@ -751,7 +752,7 @@ where
         // Extract the input globs from the build and recipe
         let mut input_globs = self.generate_recipe.extract_input_globs_from_build(
             &config,
-            &params.work_directory,
+            &self.source_dir,
             params.editable.unwrap_or_default(),
         )?;
         input_globs.append(&mut recipe.build_input_globs);
@@ -765,6 +766,30 @@ where
             subdir: *output.target_platform(),
         })
     }
+
+    async fn conda_lint(&self, params: CondaLintParams) -> miette::Result<CondaLintResult> {
+        let config = match params.lint_config {
+            Some(config) => serde_json::from_value::<T::Config>(config)
+                .into_diagnostic()
+                .context("failed to parse lint configuration")?,
+            None => self.config.clone(),
+        };
+
+        let manifest_root = if params.manifest_path.is_file() {
+            params
+                .manifest_path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        } else {
+            params.manifest_path
+        };
+
+        let diagnostics =
+            self.generate_recipe
+                .lint(&self.project_model, &config, &manifest_root)?;
+        Ok(CondaLintResult { diagnostics })
+    }
 }
 
 pub fn find_matching_output(
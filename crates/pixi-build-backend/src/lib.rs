@@ -1,11 +1,13 @@
 pub mod cli;
 pub mod generated_recipe;
 pub mod intermediate_backend;
+pub mod lint;
 pub mod protocol;
 pub mod rattler_build_integration;
 pub mod server;
 pub mod specs_conversion;
 
+pub mod about;
 pub mod cache;
 pub mod common;
 pub mod compilers;
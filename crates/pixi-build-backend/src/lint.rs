@@ -0,0 +1,82 @@
+//! The `conda/lint` procedure, which checks a package for problems that are
+//! likely to break its build, without building it.
+
+use std::{fmt, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// The name of the lint procedure.
+pub const METHOD_NAME: &str = "conda/lint";
+
+/// Parameters of the `conda/lint` procedure.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CondaLintParams {
+    /// The path to the manifest of the package to lint.
+    pub manifest_path: PathBuf,
+
+    /// The backend configuration to lint with. Defaults to the configuration
+    /// the backend was initialized with.
+    pub lint_config: Option<serde_json::Value>,
+}
+
+/// The result of the `conda/lint` procedure.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CondaLintResult {
+    /// The problems that were found, empty if the package looks fine.
+    pub diagnostics: Vec<LintDiagnostic>,
+}
+
+/// A single problem found while linting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintDiagnostic {
+    pub severity: LintSeverity,
+    pub message: String,
+    /// The file the problem was found in.
+    pub file: Option<PathBuf>,
+    /// The 1-based line in `file` the problem was found on.
+    pub line: Option<u32>,
+}
+
+/// How severe a [`LintDiagnostic`] is.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    /// The build will most likely fail.
+    Error,
+    /// The build might succeed, but not produce what is expected.
+    Warning,
+}
+
+impl fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+impl LintDiagnostic {
+    /// Returns an error diagnostic for `file`.
+    pub fn error(message: impl Into<String>, file: impl Into<Option<PathBuf>>) -> Self {
+        Self {
+            severity: LintSeverity::Error,
+            message: message.into(),
+            file: file.into(),
+            line: None,
+        }
+    }
+
+    /// Returns a warning diagnostic for `file`.
+    pub fn warning(message: impl Into<String>, file: impl Into<Option<PathBuf>>) -> Self {
+        Self {
+            severity: LintSeverity::Warning,
+            message: message.into(),
+            file: file.into(),
+            line: None,
+        }
+    }
+}
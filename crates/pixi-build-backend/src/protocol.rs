@@ -1,9 +1,24 @@
+use std::path::PathBuf;
+
 use pixi_build_types::procedures::conda_build_v1::{CondaBuildV1Params, CondaBuildV1Result};
 use pixi_build_types::procedures::conda_outputs::{CondaOutputsParams, CondaOutputsResult};
 use pixi_build_types::procedures::{
     initialize::{InitializeParams, InitializeResult},
     negotiate_capabilities::{NegotiateCapabilitiesParams, NegotiateCapabilitiesResult},
 };
+use serde::Deserialize;
+
+use crate::lint::{CondaLintParams, CondaLintResult};
+
+/// The minimal subset of a backend configuration that is required to extract
+/// the `debug-dir`. All other fields of the configuration are ignored.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DebugDirConfig {
+    /// The debug directory as specified in the backend configuration.
+    #[serde(alias = "debug_dir")]
+    pub debug_dir: Option<PathBuf>,
+}
 
 /// A trait that is used to instantiate a new protocol connection
 /// and endpoint that can handle the RPC calls.
@@ -21,6 +36,20 @@ pub trait ProtocolInstantiator: Send + Sync + 'static {
         &self,
         params: InitializeParams,
     ) -> miette::Result<(Box<dyn Protocol + Send + Sync + 'static>, InitializeResult)>;
+
+    /// Extracts the debug directory from the backend configuration.
+    ///
+    /// The default implementation deserializes the configuration as a
+    /// [`DebugDirConfig`]. Backends only need to override this if they store
+    /// the debug directory differently.
+    fn debug_dir(configuration: Option<serde_json::Value>) -> Option<PathBuf>
+    where
+        Self: Sized,
+    {
+        configuration
+            .and_then(|config| serde_json::from_value::<DebugDirConfig>(config).ok())
+            .and_then(|config| config.debug_dir)
+    }
 }
 
 /// A trait that defines the protocol for a pixi build backend.
@@ -43,4 +72,38 @@ pub trait Protocol {
     ) -> miette::Result<CondaBuildV1Result> {
         unimplemented!("conda_build_v1 not implemented");
     }
+
+    /// Called when the client calls `conda/lint`. The default implementation
+    /// finds no problems.
+    async fn conda_lint(&self, _params: CondaLintParams) -> miette::Result<CondaLintResult> {
+        Ok(CondaLintResult::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use super::DebugDirConfig;
+
+    #[test]
+    fn test_debug_dir_config_ignores_other_fields() {
+        let config: DebugDirConfig = serde_json::from_value(json!({
+            "debug-dir": "/some/debug",
+            "extra-args": ["--foo"],
+        }))
+        .unwrap();
+        assert_eq!(config.debug_dir, Some(PathBuf::from("/some/debug")));
+
+        let config: DebugDirConfig = serde_json::from_value(json!({
+            "debug_dir": "/other/debug",
+        }))
+        .unwrap();
+        assert_eq!(config.debug_dir, Some(PathBuf::from("/other/debug")));
+
+        let config: DebugDirConfig = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(config.debug_dir, None);
+    }
 }
@@ -17,6 +17,7 @@ use serde::Serialize;
 use tokio::sync::{Mutex, RwLock};
 
 use crate::consts::DEBUG_OUTPUT_DIR;
+use crate::lint::{self, CondaLintParams};
 use crate::protocol::{Protocol, ProtocolInstantiator};
 
 /// A JSONRPC server that can be used to communicate with a client.
@@ -65,6 +66,59 @@ impl<T: ProtocolInstantiator> Server<T> {
         Ok(())
     }
 
+    /// Run the server, communicating over a UNIX domain socket at `path`.
+    /// Every connection receives newline delimited json-rpc requests and
+    /// replies with a newline delimited response for each of them. The server
+    /// stops on `SIGINT` or `SIGTERM` and removes the socket file again.
+    #[cfg(unix)]
+    pub async fn run_over_unix_socket(self, path: &Path) -> miette::Result<()> {
+        use tokio::{
+            io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+            signal::unix::{SignalKind, signal},
+        };
+
+        remove_stale_unix_socket(path).await?;
+
+        let listener = tokio::net::UnixListener::bind(path)
+            .into_diagnostic()
+            .with_context(|| format!("failed to bind unix socket {}", path.display()))?;
+        let _socket_file = UnixSocketFile(path);
+
+        let mut terminate = signal(SignalKind::terminate()).into_diagnostic()?;
+        let io = Arc::new(self.setup_io());
+        loop {
+            let (stream, _) = tokio::select! {
+                accepted = listener.accept() => accepted.into_diagnostic()?,
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+                _ = terminate.recv() => return Ok(()),
+            };
+            let io = io.clone();
+            tokio::spawn(async move {
+                let (reader, mut writer) = stream.into_split();
+                let mut lines = BufReader::new(reader).lines();
+                while let Ok(Some(request)) = lines.next_line().await {
+                    let Some(mut response) = io.handle_request(&request).await else {
+                        continue;
+                    };
+                    response.push('\n');
+                    if writer.write_all(response.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+    }
+
+    /// Run the server, communicating over a UNIX domain socket at `path`.
+    /// UNIX domain sockets are not supported on this platform.
+    #[cfg(not(unix))]
+    pub async fn run_over_unix_socket(self, path: &Path) -> miette::Result<()> {
+        miette::bail!(
+            "cannot listen on {}, unix domain sockets are not supported on this platform",
+            path.display()
+        )
+    }
+
     /// Setup the IO inner handler.
     fn setup_io(self) -> IoHandler {
         // Construct a server
@@ -205,10 +259,59 @@ impl<T: ProtocolInstantiator> Server<T> {
             },
         );
 
+        let conda_lint = state.clone();
+        io.add_method(lint::METHOD_NAME, move |params: Params| {
+            let state = conda_lint.clone();
+
+            async move {
+                let params: CondaLintParams = params.parse()?;
+                let state = state.read().await;
+                let endpoint = state.as_endpoint()?;
+
+                let result = endpoint.conda_lint(params).await.map_err(convert_error)?;
+                Ok(to_value(result).expect("failed to convert to json"))
+            }
+        });
+
         io
     }
 }
 
+/// Removes a socket file left behind by a previous run, which would prevent
+/// binding. Any other kind of file at `path` is left alone and reported as an
+/// error.
+#[cfg(unix)]
+async fn remove_stale_unix_socket(path: &Path) -> miette::Result<()> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let metadata = match tokio_fs::symlink_metadata(path).await {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err).into_diagnostic(),
+    };
+    if !metadata.file_type().is_socket() {
+        miette::bail!(
+            "cannot bind unix socket {}, the path already exists and is not a socket",
+            path.display()
+        );
+    }
+    tokio_fs::remove_file(path)
+        .await
+        .into_diagnostic()
+        .context("failed to remove the stale unix socket")
+}
+
+/// Removes the socket file of the server when it stops.
+#[cfg(unix)]
+struct UnixSocketFile<'a>(&'a Path);
+
+#[cfg(unix)]
+impl Drop for UnixSocketFile<'_> {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(self.0);
+    }
+}
+
 fn convert_error(err: miette::Report) -> jsonrpc_core::Error {
     let rendered = JSONReportHandler::new();
     let mut json_str = String::new();
@@ -291,3 +394,28 @@ async fn write_json_file<T: Serialize>(
         .context("failed to write JSON to file")?;
     Ok(())
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::remove_stale_unix_socket;
+
+    #[tokio::test]
+    async fn test_only_stale_sockets_are_removed() {
+        let tmp = tempfile::tempdir().unwrap();
+
+        let socket = tmp.path().join("backend.sock");
+        drop(std::os::unix::net::UnixListener::bind(&socket).unwrap());
+        assert!(socket.exists());
+        remove_stale_unix_socket(&socket).await.unwrap();
+        assert!(!socket.exists());
+
+        // A missing path is fine.
+        remove_stale_unix_socket(&socket).await.unwrap();
+
+        let file = tmp.path().join("backend.txt");
+        std::fs::write(&file, "not a socket").unwrap();
+        let err = remove_stale_unix_socket(&file).await.unwrap_err();
+        assert!(err.to_string().contains("is not a socket"));
+        assert!(file.exists());
+    }
+}
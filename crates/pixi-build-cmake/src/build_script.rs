@@ -1,6 +1,8 @@
 use minijinja::Environment;
 use serde::Serialize;
 
+use crate::{config::StripMode, toolchain::CMakeToolchain};
+
 #[derive(Serialize)]
 pub struct BuildScriptContext {
     pub build_platform: BuildPlatform,
@@ -10,6 +12,56 @@ pub struct BuildScriptContext {
     /// This is used to determine if the build script
     /// should include Python-related logic.
     pub has_host_python: bool,
+    /// The cmake preset to configure, build and install with instead of the
+    /// default arguments.
+    pub preset: Option<String>,
+    /// The build directory of the cmake preset, `build` is used if the preset
+    /// does not define one.
+    pub preset_binary_dir: Option<String>,
+    /// The compiler cache executable to use as C and C++ compiler launcher.
+    pub compiler_launcher: Option<String>,
+    /// Paths appended to `CMAKE_PREFIX_PATH` after the host and build
+    /// prefixes.
+    pub extra_cmake_prefix_paths: Vec<String>,
+    /// Run the CTest tests after the install.
+    pub run_tests: bool,
+    /// The timeout of a single CTest test.
+    pub test_timeout_seconds: Option<u32>,
+    /// Run every CTest test in its own ctest invocation.
+    pub per_test_output: bool,
+    /// The toolchain to write to `pixi_toolchain.cmake` and configure with
+    /// when cross-compiling.
+    pub toolchain: Option<CMakeToolchain>,
+    /// The `CMAKE_INSTALL_LIBDIR` to install libraries into. When set,
+    /// executables are installed into `bin`.
+    pub install_libdir: Option<String>,
+    /// The CUDA architectures to compile for, set if the package is built
+    /// with the CUDA compiler.
+    pub cuda_architectures: Option<String>,
+    /// The `CMAKE_INSTALL_RPATH` of the installed libraries and executables.
+    pub install_rpath: Option<String>,
+    /// Pass the OpenMP compiler flags to `FindOpenMP`.
+    pub openmp: bool,
+    /// The cmake generator to configure with, e.g. `Ninja`.
+    pub generator: String,
+    /// The vcpkg triplet to install the dependencies of the `vcpkg.json`
+    /// manifest for, set if the package is built with vcpkg.
+    pub vcpkg_triplet: Option<String>,
+    /// The cmake install components to install one after another instead of
+    /// installing everything.
+    pub install_components: Vec<String>,
+    /// The maximum number of parallel build jobs, the number of CPUs is used
+    /// if it is lower.
+    pub max_parallel_jobs: Option<usize>,
+    /// The `CMAKE_MESSAGE_LOG_LEVEL` to configure with.
+    pub log_level: Option<String>,
+    /// Trace the `find_*` calls of cmake.
+    pub find_debug: bool,
+    /// The installed binaries to strip the debug symbols from.
+    pub strip_mode: StripMode,
+    /// Configure without downloading or updating the `FetchContent`
+    /// dependencies.
+    pub fetchcontent_offline: bool,
 }
 
 #[derive(Copy, Clone, Serialize)]
@@ -33,9 +85,42 @@ impl BuildScriptContext {
 
 #[cfg(test)]
 mod test {
+    use rattler_conda_types::Platform;
     use rstest::*;
 
     use super::*;
+    use crate::toolchain::platform_to_cmake_system;
+
+    impl Default for BuildScriptContext {
+        fn default() -> Self {
+            Self {
+                build_platform: BuildPlatform::Unix,
+                source_dir: String::from("my-prefix-dir"),
+                extra_args: vec![],
+                has_host_python: false,
+                preset: None,
+                preset_binary_dir: None,
+                compiler_launcher: None,
+                extra_cmake_prefix_paths: vec![],
+                run_tests: false,
+                test_timeout_seconds: None,
+                per_test_output: false,
+                toolchain: None,
+                install_libdir: Some(String::from("lib")),
+                cuda_architectures: None,
+                install_rpath: None,
+                openmp: false,
+                generator: String::from("Ninja"),
+                vcpkg_triplet: None,
+                install_components: vec![],
+                max_parallel_jobs: None,
+                log_level: None,
+                find_debug: false,
+                strip_mode: StripMode::None,
+                fetchcontent_offline: false,
+            }
+        }
+    }
 
     #[rstest]
     fn test_build_script(
@@ -45,9 +130,9 @@ mod test {
     ) {
         let context = BuildScriptContext {
             build_platform,
-            source_dir: String::from("my-prefix-dir"),
             extra_args: extra_args.clone(),
             has_host_python,
+            ..Default::default()
         };
         let script = context.render();
 
@@ -70,4 +155,372 @@ mod test {
             insta::assert_snapshot!(script);
         });
     }
+
+    #[rstest]
+    fn test_build_script_with_preset(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            extra_args: vec![String::from("test-arg")],
+            preset: Some(String::from("release")),
+            preset_binary_dir: Some(String::from("my-prefix-dir/out/release")),
+            ..Default::default()
+        }
+        .render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(build_platform.to_string());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_build_script_with_compiler_launcher(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            compiler_launcher: Some(String::from("sccache")),
+            ..Default::default()
+        }
+        .render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(build_platform.to_string());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_build_script_with_tests(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            run_tests: true,
+            test_timeout_seconds: Some(120),
+            per_test_output: true,
+            ..Default::default()
+        }
+        .render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(build_platform.to_string());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_build_script_with_cuda(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            cuda_architectures: Some(String::from("all-major")),
+            ..Default::default()
+        }
+        .render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(build_platform.to_string());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_build_script_with_openmp(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            openmp: true,
+            ..Default::default()
+        }
+        .render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(build_platform.to_string());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_build_script_with_strip_debug_symbols(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            strip_mode: StripMode::All,
+            ..Default::default()
+        }
+        .render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(build_platform.to_string());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    #[case(BuildPlatform::Unix, Platform::LinuxAarch64)]
+    #[case(BuildPlatform::Windows, Platform::WinArm64)]
+    fn test_build_script_cross_compile(
+        #[case] build_platform: BuildPlatform,
+        #[case] host_platform: Platform,
+    ) {
+        let (system_name, system_processor) = platform_to_cmake_system(host_platform).unwrap();
+        let script = BuildScriptContext {
+            build_platform,
+            toolchain: Some(CMakeToolchain {
+                system_name,
+                system_processor,
+            }),
+            ..Default::default()
+        }
+        .render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(host_platform.as_str());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_cmake_prefix_path_is_set(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            extra_args: vec![String::from("-DCMAKE_PREFIX_PATH=/override")],
+            extra_cmake_prefix_paths: vec![String::from("/opt/extra")],
+            ..Default::default()
+        }
+        .render();
+
+        let expected = match build_platform {
+            BuildPlatform::Windows => {
+                r#"-DCMAKE_PREFIX_PATH="%LIBRARY_PREFIX%;%BUILD_PREFIX%\Library;/opt/extra""#
+            }
+            BuildPlatform::Unix => r#"-DCMAKE_PREFIX_PATH="$PREFIX;$BUILD_PREFIX;/opt/extra""#,
+        };
+        let prefix_path = script
+            .find(expected)
+            .expect("CMAKE_PREFIX_PATH should be set in the build script");
+
+        // The user can override the prefix path through `extra-args`.
+        let user_prefix_path = script
+            .find("-DCMAKE_PREFIX_PATH=/override")
+            .expect("extra args should be passed to cmake");
+        assert!(prefix_path < user_prefix_path);
+    }
+
+    #[rstest]
+    fn test_install_libdir(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let render = |install_libdir: Option<&str>| {
+            BuildScriptContext {
+                build_platform,
+                install_libdir: install_libdir.map(String::from),
+                ..Default::default()
+            }
+            .render()
+        };
+
+        let script = render(Some("lib64"));
+        assert!(script.contains("-DCMAKE_INSTALL_LIBDIR=lib64"));
+        assert!(script.contains("-DCMAKE_INSTALL_BINDIR=bin"));
+
+        let script = render(None);
+        assert!(!script.contains("CMAKE_INSTALL_LIBDIR"));
+        assert!(!script.contains("CMAKE_INSTALL_BINDIR"));
+    }
+
+    #[rstest]
+    fn test_generator(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            generator: String::from("Unix Makefiles"),
+            ..Default::default()
+        }
+        .render();
+
+        assert!(script.contains(r#"-G "Unix Makefiles""#));
+        assert!(script.contains("CMakeCache.txt"));
+        assert!(!script.contains("ninja"));
+        assert!(!script.contains("CMAKE_MAKE_PROGRAM"));
+    }
+
+    #[test]
+    fn test_install_rpath_is_escaped() {
+        let script = BuildScriptContext {
+            install_rpath: Some(String::from("$ORIGIN/../lib")),
+            ..Default::default()
+        }
+        .render();
+
+        assert!(script.contains(r#"-DCMAKE_INSTALL_RPATH="\$ORIGIN/../lib""#));
+        assert!(script.contains("-DCMAKE_BUILD_WITH_INSTALL_RPATH=ON"));
+    }
+
+    #[rstest]
+    #[case(
+        BuildPlatform::Windows,
+        r"PKG_CONFIG_PATH=%LIBRARY_PREFIX%\lib\pkgconfig;"
+    )]
+    #[case(BuildPlatform::Unix, r#"PKG_CONFIG_PATH="$PREFIX/lib/pkgconfig:"#)]
+    fn test_pkg_config_path_is_set_before_cmake(
+        #[case] build_platform: BuildPlatform,
+        #[case] host_pkg_config_path: &str,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            ..Default::default()
+        }
+        .render();
+
+        let pkg_config_path = script
+            .find("PKG_CONFIG_PATH=")
+            .expect("PKG_CONFIG_PATH should be set in the build script");
+        let cmake_invocation = script
+            .find("cmake %CMAKE_ARGS%")
+            .or_else(|| script.find("cmake $CMAKE_ARGS"))
+            .expect("cmake should be invoked in the build script");
+        assert!(pkg_config_path < cmake_invocation);
+
+        // The pkg-config files of the host environment come first
+        assert!(script.contains(host_pkg_config_path), "{script}");
+    }
+
+    #[test]
+    fn test_vcpkg_chainloads_toolchain() {
+        let script = BuildScriptContext {
+            toolchain: Some(CMakeToolchain {
+                system_name: String::from("Linux"),
+                system_processor: String::from("aarch64"),
+            }),
+            vcpkg_triplet: Some(String::from("arm64-linux")),
+            ..Default::default()
+        }
+        .render();
+
+        assert!(script.contains(
+            r#"-DCMAKE_TOOLCHAIN_FILE="$BUILD_PREFIX/scripts/buildsystems/vcpkg.cmake""#
+        ));
+        assert!(script.contains("-DVCPKG_TARGET_TRIPLET=arm64-linux"));
+        assert!(
+            script.contains(r#"-DVCPKG_CHAINLOAD_TOOLCHAIN_FILE="$SRC_DIR/pixi_toolchain.cmake""#)
+        );
+    }
+
+    #[rstest]
+    fn test_install_components(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            install_components: vec![String::from("Runtime"), String::from("Development")],
+            ..Default::default()
+        }
+        .render();
+
+        assert!(!script.contains("--target install"));
+        let runtime = script
+            .find("cmake --install . --component Runtime")
+            .expect("the Runtime component should be installed");
+        let development = script
+            .find("cmake --install . --component Development")
+            .expect("the Development component should be installed");
+        assert!(runtime < development);
+    }
+
+    #[rstest]
+    #[case(
+        BuildPlatform::Unix,
+        "export CMAKE_BUILD_PARALLEL_LEVEL=$(( CPU_COUNT < 4 ? CPU_COUNT : 4 ))"
+    )]
+    #[case(
+        BuildPlatform::Windows,
+        r#"if %CMAKE_BUILD_PARALLEL_LEVEL% GTR 4 set "CMAKE_BUILD_PARALLEL_LEVEL=4""#
+    )]
+    fn test_max_parallel_jobs(#[case] build_platform: BuildPlatform, #[case] expected: &str) {
+        let script = BuildScriptContext {
+            build_platform,
+            generator: String::from("Unix Makefiles"),
+            max_parallel_jobs: Some(4),
+            ..Default::default()
+        }
+        .render();
+
+        assert!(script.contains(expected), "{script}");
+        assert!(script.contains(" -- -j"), "{script}");
+    }
+
+    #[test]
+    fn test_max_parallel_jobs_nmake() {
+        let script = BuildScriptContext {
+            build_platform: BuildPlatform::Windows,
+            generator: String::from("NMake Makefiles"),
+            max_parallel_jobs: Some(4),
+            ..Default::default()
+        }
+        .render();
+
+        assert!(script.contains("CMAKE_BUILD_PARALLEL_LEVEL=4"), "{script}");
+        assert!(!script.contains(" -- -j"), "{script}");
+    }
+
+    #[rstest]
+    fn test_debug_output(
+        #[values(BuildPlatform::Unix, BuildPlatform::Windows)] build_platform: BuildPlatform,
+        #[values(None, Some("release"))] preset: Option<&str>,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            preset: preset.map(String::from),
+            log_level: Some(String::from("VERBOSE")),
+            find_debug: true,
+            ..Default::default()
+        }
+        .render();
+
+        assert!(
+            script.contains("-DCMAKE_MESSAGE_LOG_LEVEL=VERBOSE"),
+            "{script}"
+        );
+        assert!(script.contains("-DCMAKE_FIND_DEBUG_MODE=ON"), "{script}");
+    }
+
+    #[rstest]
+    fn test_fetchcontent_offline(
+        #[values(BuildPlatform::Unix, BuildPlatform::Windows)] build_platform: BuildPlatform,
+        #[values(None, Some("release"))] preset: Option<&str>,
+    ) {
+        let script = BuildScriptContext {
+            build_platform,
+            preset: preset.map(String::from),
+            fetchcontent_offline: true,
+            ..Default::default()
+        }
+        .render();
+
+        assert!(
+            script.contains("-DFETCHCONTENT_FULLY_DISCONNECTED=ON"),
+            "{script}"
+        );
+        assert!(
+            script.contains("-DFETCHCONTENT_UPDATES_DISCONNECTED=ON"),
+            "{script}"
+        );
+    }
 }
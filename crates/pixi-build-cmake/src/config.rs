@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use indexmap::IndexMap;
 use pixi_build_backend::generated_recipe::BackendConfig;
+use rattler_conda_types::Platform;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
@@ -20,9 +21,165 @@ pub struct CMakeBackendConfig {
     /// Extra input globs to include in addition to the default ones
     #[serde(default)]
     pub extra_input_globs: Vec<String>,
+    /// Globs of input files to exclude from the default and extra input
+    /// globs, e.g. `build/**`. Uses the same glob syntax as the
+    /// input globs.
+    #[serde(default)]
+    pub exclude_input_globs: Vec<String>,
     /// List of compilers to use (e.g., ["c", "cxx", "cuda"])
     /// If not specified, a default will be used
     pub compilers: Option<Vec<String>>,
+    /// Name of a preset from `CMakePresets.json` or `CMakeUserPresets.json`
+    /// to configure, build and install with. When set, the preset replaces
+    /// the default cmake arguments.
+    pub cmake_preset: Option<String>,
+    /// Compiler cache to use as the C and C++ compiler launcher
+    pub compiler_cache: Option<CompilerCacheKind>,
+    /// Extra paths to append to `CMAKE_PREFIX_PATH`, after the host and
+    /// build prefixes
+    #[serde(default)]
+    pub extra_cmake_prefix_paths: Vec<String>,
+    /// Run the CTest tests with `ctest` after the install. Defaults to
+    /// `false`.
+    pub run_tests: Option<bool>,
+    /// The timeout in seconds of a single CTest test
+    pub test_timeout_seconds: Option<u32>,
+    /// Run every CTest test in its own ctest invocation, so each test reports
+    /// its own result and a failing test does not stop the others
+    pub per_test_output: Option<bool>,
+    /// Generate a cmake toolchain file for the host platform when
+    /// cross-compiling. Defaults to `true`.
+    pub generate_toolchain: Option<bool>,
+    /// Pass `CMAKE_INSTALL_LIBDIR` and `CMAKE_INSTALL_BINDIR` so libraries
+    /// and executables are installed into `lib` and `bin`. Defaults to
+    /// `true`.
+    pub override_install_dirs: Option<bool>,
+    /// The `CMAKE_INSTALL_LIBDIR` to use instead of `lib`, e.g. `lib64`
+    pub cmake_install_dir_override: Option<String>,
+    /// The `CMAKE_CUDA_ARCHITECTURES` to build for when the CUDA compiler is
+    /// a build dependency. Defaults to `all-major`.
+    pub cuda_architectures: Option<String>,
+    /// The `CMAKE_INSTALL_RPATH` to use instead of the platform default,
+    /// `$ORIGIN/../lib` on Linux and `@loader_path/../lib` on macOS
+    pub custom_rpath: Option<String>,
+    /// Do not set `CMAKE_INSTALL_RPATH`. Defaults to `false`.
+    pub disable_rpath: Option<bool>,
+    /// Do not warn if the `CMakeLists.txt` downloads dependencies at
+    /// configure time. Defaults to `false`.
+    pub allow_network_downloads: Option<bool>,
+    /// Build with OpenMP. Defaults to whether the `CMakeLists.txt` calls
+    /// `find_package(OpenMP)`.
+    pub enable_openmp: Option<bool>,
+    /// The cmake generator, e.g. `Unix Makefiles`. Defaults to `Ninja`.
+    pub generator: Option<String>,
+    /// Install the dependencies of a `vcpkg.json` manifest with vcpkg.
+    /// Defaults to `false`.
+    pub use_vcpkg: Option<bool>,
+    /// The vcpkg triplet to install dependencies for. Defaults to the
+    /// triplet of the host platform, e.g. `x64-linux`.
+    pub vcpkg_triplet: Option<String>,
+    /// The cmake install components to install, e.g. `Runtime`. Defaults to
+    /// installing everything.
+    #[serde(default)]
+    pub install_components: Vec<String>,
+    /// The maximum number of parallel build jobs. Defaults to the number of
+    /// CPUs.
+    pub max_parallel_jobs: Option<usize>,
+    /// The `CMAKE_MESSAGE_LOG_LEVEL` to configure with, e.g. `VERBOSE` or
+    /// `DEBUG`.
+    pub cmake_log_level: Option<String>,
+    /// Trace the `find_*` calls of cmake (`CMAKE_FIND_DEBUG_MODE`).
+    pub cmake_find_debug: Option<bool>,
+    /// Which of the installed binaries to strip the debug symbols from.
+    /// Defaults to not stripping anything.
+    pub strip_debug_symbols: Option<StripMode>,
+    /// Do not download or update the `FetchContent` dependencies at configure
+    /// time (`FETCHCONTENT_FULLY_DISCONNECTED`). Defaults to `false`.
+    pub fetchcontent_offline: Option<bool>,
+}
+
+/// The log levels that `CMAKE_MESSAGE_LOG_LEVEL` accepts.
+const CMAKE_LOG_LEVELS: [&str; 7] = [
+    "ERROR", "WARNING", "NOTICE", "STATUS", "VERBOSE", "DEBUG", "TRACE",
+];
+
+/// A compiler cache that cmake can use as a compiler launcher.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CompilerCacheKind {
+    Sccache,
+    Ccache,
+}
+
+impl CompilerCacheKind {
+    /// Returns the name of the executable, which is also the name of the
+    /// conda package that provides it.
+    pub fn executable(&self) -> &'static str {
+        match self {
+            CompilerCacheKind::Sccache => "sccache",
+            CompilerCacheKind::Ccache => "ccache",
+        }
+    }
+}
+
+/// The installed binaries to strip the debug symbols from.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum StripMode {
+    /// Keep all debug symbols.
+    #[default]
+    None,
+    /// Strip the executables in `bin`.
+    Executables,
+    /// Strip the executables in `bin` and the shared libraries.
+    All,
+}
+
+impl CMakeBackendConfig {
+    /// Returns the `CMAKE_INSTALL_RPATH` to pass to cmake when building for
+    /// `host_platform`, or `None` if no RPATH should be set.
+    pub fn install_rpath(&self, host_platform: Platform) -> Option<String> {
+        if self.disable_rpath.is_some_and(|disable| disable) {
+            return None;
+        }
+        if let Some(custom_rpath) = &self.custom_rpath {
+            return Some(custom_rpath.clone());
+        }
+        if host_platform.is_linux() {
+            Some("$ORIGIN/../lib".to_string())
+        } else if host_platform.is_osx() {
+            Some("@loader_path/../lib".to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the `CMAKE_INSTALL_LIBDIR` to pass to cmake, or `None` if the
+    /// install directories should not be overridden.
+    pub fn install_libdir(&self) -> Option<String> {
+        self.override_install_dirs.unwrap_or(true).then(|| {
+            self.cmake_install_dir_override
+                .clone()
+                .unwrap_or_else(|| "lib".to_string())
+        })
+    }
+
+    /// Returns the `CMAKE_MESSAGE_LOG_LEVEL` to pass to cmake, or an error if
+    /// the configured log level is not known to cmake.
+    pub fn message_log_level(&self) -> miette::Result<Option<String>> {
+        let Some(log_level) = &self.cmake_log_level else {
+            return Ok(None);
+        };
+        let log_level = log_level.to_uppercase();
+        if !CMAKE_LOG_LEVELS.contains(&log_level.as_str()) {
+            miette::bail!(
+                "`cmake-log-level` must be one of {}, got `{}`",
+                CMAKE_LOG_LEVELS.join(", "),
+                self.cmake_log_level.as_deref().unwrap_or_default()
+            );
+        }
+        Ok(Some(log_level))
+    }
 }
 
 impl BackendConfig for CMakeBackendConfig {
@@ -36,7 +193,32 @@ impl BackendConfig for CMakeBackendConfig {
     /// - env: Platform env vars override base, others merge
     /// - debug_dir: Not allowed to have target specific value
     /// - extra_input_globs: Platform-specific completely replaces base
+    /// - exclude_input_globs: Platform-specific completely replaces base
     /// - compilers: Platform-specific completely replaces base
+    /// - cmake_preset: Platform-specific completely replaces base
+    /// - compiler_cache: Platform-specific completely replaces base
+    /// - extra_cmake_prefix_paths: Platform-specific completely replaces base
+    /// - run_tests: Platform-specific takes precedence over base
+    /// - test_timeout_seconds: Platform-specific takes precedence over base
+    /// - per_test_output: Platform-specific takes precedence over base
+    /// - generate_toolchain: Platform-specific takes precedence over base
+    /// - override_install_dirs: Platform-specific takes precedence over base
+    /// - cmake_install_dir_override: Platform-specific takes precedence over
+    ///   base
+    /// - cuda_architectures: Platform-specific takes precedence over base
+    /// - custom_rpath: Platform-specific takes precedence over base
+    /// - disable_rpath: Platform-specific takes precedence over base
+    /// - allow_network_downloads: Platform-specific takes precedence over base
+    /// - enable_openmp: Platform-specific takes precedence over base
+    /// - generator: Platform-specific takes precedence over base
+    /// - use_vcpkg: Platform-specific takes precedence over base
+    /// - vcpkg_triplet: Platform-specific takes precedence over base
+    /// - install_components: Platform-specific completely replaces base
+    /// - max_parallel_jobs: Platform-specific takes precedence over base
+    /// - cmake_log_level: Platform-specific takes precedence over base
+    /// - cmake_find_debug: Platform-specific takes precedence over base
+    /// - strip_debug_symbols: Platform-specific takes precedence over base
+    /// - fetchcontent_offline: Platform-specific takes precedence over base
     fn merge_with_target_config(&self, target_config: &Self) -> miette::Result<Self> {
         if target_config.debug_dir.is_some() {
             miette::bail!("`debug_dir` cannot have a target specific value");
@@ -59,10 +241,77 @@ impl BackendConfig for CMakeBackendConfig {
             } else {
                 target_config.extra_input_globs.clone()
             },
+            exclude_input_globs: if target_config.exclude_input_globs.is_empty() {
+                self.exclude_input_globs.clone()
+            } else {
+                target_config.exclude_input_globs.clone()
+            },
             compilers: target_config
                 .compilers
                 .clone()
                 .or_else(|| self.compilers.clone()),
+            cmake_preset: target_config
+                .cmake_preset
+                .clone()
+                .or_else(|| self.cmake_preset.clone()),
+            compiler_cache: target_config.compiler_cache.or(self.compiler_cache),
+            extra_cmake_prefix_paths: if target_config.extra_cmake_prefix_paths.is_empty() {
+                self.extra_cmake_prefix_paths.clone()
+            } else {
+                target_config.extra_cmake_prefix_paths.clone()
+            },
+            run_tests: target_config.run_tests.or(self.run_tests),
+            test_timeout_seconds: target_config
+                .test_timeout_seconds
+                .or(self.test_timeout_seconds),
+            per_test_output: target_config.per_test_output.or(self.per_test_output),
+            generate_toolchain: target_config.generate_toolchain.or(self.generate_toolchain),
+            override_install_dirs: target_config
+                .override_install_dirs
+                .or(self.override_install_dirs),
+            cmake_install_dir_override: target_config
+                .cmake_install_dir_override
+                .clone()
+                .or_else(|| self.cmake_install_dir_override.clone()),
+            cuda_architectures: target_config
+                .cuda_architectures
+                .clone()
+                .or_else(|| self.cuda_architectures.clone()),
+            custom_rpath: target_config
+                .custom_rpath
+                .clone()
+                .or_else(|| self.custom_rpath.clone()),
+            disable_rpath: target_config.disable_rpath.or(self.disable_rpath),
+            allow_network_downloads: target_config
+                .allow_network_downloads
+                .or(self.allow_network_downloads),
+            enable_openmp: target_config.enable_openmp.or(self.enable_openmp),
+            generator: target_config
+                .generator
+                .clone()
+                .or_else(|| self.generator.clone()),
+            use_vcpkg: target_config.use_vcpkg.or(self.use_vcpkg),
+            vcpkg_triplet: target_config
+                .vcpkg_triplet
+                .clone()
+                .or_else(|| self.vcpkg_triplet.clone()),
+            install_components: if target_config.install_components.is_empty() {
+                self.install_components.clone()
+            } else {
+                target_config.install_components.clone()
+            },
+            max_parallel_jobs: target_config.max_parallel_jobs.or(self.max_parallel_jobs),
+            cmake_log_level: target_config
+                .cmake_log_level
+                .clone()
+                .or_else(|| self.cmake_log_level.clone()),
+            cmake_find_debug: target_config.cmake_find_debug.or(self.cmake_find_debug),
+            strip_debug_symbols: target_config
+                .strip_debug_symbols
+                .or(self.strip_debug_symbols),
+            fetchcontent_offline: target_config
+                .fetchcontent_offline
+                .or(self.fetchcontent_offline),
         })
     }
 }
@@ -73,7 +322,9 @@ mod tests {
     use serde_json::json;
     use std::path::PathBuf;
 
-    use super::CMakeBackendConfig;
+    use rattler_conda_types::Platform;
+
+    use super::{CMakeBackendConfig, CompilerCacheKind, StripMode};
 
     #[test]
     fn test_ensure_deseralize_from_empty() {
@@ -92,7 +343,31 @@ mod tests {
             env: base_env,
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             compilers: Some(vec!["cxx".to_string()]),
+            cmake_preset: None,
+            compiler_cache: None,
+            extra_cmake_prefix_paths: vec![],
+            run_tests: None,
+            test_timeout_seconds: None,
+            per_test_output: None,
+            generate_toolchain: None,
+            override_install_dirs: None,
+            cmake_install_dir_override: None,
+            cuda_architectures: None,
+            custom_rpath: None,
+            disable_rpath: None,
+            allow_network_downloads: None,
+            enable_openmp: None,
+            generator: None,
+            use_vcpkg: None,
+            vcpkg_triplet: None,
+            install_components: vec![],
+            max_parallel_jobs: None,
+            cmake_log_level: None,
+            cmake_find_debug: None,
+            strip_debug_symbols: None,
+            fetchcontent_offline: None,
         };
 
         let mut target_env = indexmap::IndexMap::new();
@@ -104,7 +379,31 @@ mod tests {
             env: target_env,
             debug_dir: None,
             extra_input_globs: vec!["*.target".to_string()],
+            exclude_input_globs: vec![],
             compilers: Some(vec!["c".to_string(), "cuda".to_string()]),
+            cmake_preset: None,
+            compiler_cache: None,
+            extra_cmake_prefix_paths: vec![],
+            run_tests: None,
+            test_timeout_seconds: None,
+            per_test_output: None,
+            generate_toolchain: None,
+            override_install_dirs: None,
+            cmake_install_dir_override: None,
+            cuda_architectures: None,
+            custom_rpath: None,
+            disable_rpath: None,
+            allow_network_downloads: None,
+            enable_openmp: None,
+            generator: None,
+            use_vcpkg: None,
+            vcpkg_triplet: None,
+            install_components: vec![],
+            max_parallel_jobs: None,
+            cmake_log_level: None,
+            cmake_find_debug: None,
+            strip_debug_symbols: None,
+            fetchcontent_offline: None,
         };
 
         let merged = base_config
@@ -148,7 +447,31 @@ mod tests {
             env: base_env,
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             compilers: Some(vec!["cxx".to_string()]),
+            cmake_preset: None,
+            compiler_cache: None,
+            extra_cmake_prefix_paths: vec![],
+            run_tests: None,
+            test_timeout_seconds: None,
+            per_test_output: None,
+            generate_toolchain: None,
+            override_install_dirs: None,
+            cmake_install_dir_override: None,
+            cuda_architectures: None,
+            custom_rpath: None,
+            disable_rpath: None,
+            allow_network_downloads: None,
+            enable_openmp: None,
+            generator: None,
+            use_vcpkg: None,
+            vcpkg_triplet: None,
+            install_components: vec![],
+            max_parallel_jobs: None,
+            cmake_log_level: None,
+            cmake_find_debug: None,
+            strip_debug_symbols: None,
+            fetchcontent_offline: None,
         };
 
         let empty_target_config = CMakeBackendConfig::default();
@@ -182,4 +505,118 @@ mod tests {
         let error_msg = result.unwrap_err().to_string();
         assert!(error_msg.contains("`debug_dir` cannot have a target specific value"));
     }
+
+    #[test]
+    fn test_compiler_cache_deserialize() {
+        let config = serde_json::from_value::<CMakeBackendConfig>(json!({
+            "compiler-cache": "sccache"
+        }))
+        .unwrap();
+        assert_eq!(config.compiler_cache, Some(CompilerCacheKind::Sccache));
+
+        let config = serde_json::from_value::<CMakeBackendConfig>(json!({
+            "compiler-cache": "ccache"
+        }))
+        .unwrap();
+        assert_eq!(config.compiler_cache, Some(CompilerCacheKind::Ccache));
+
+        assert!(
+            serde_json::from_value::<CMakeBackendConfig>(json!({
+                "compiler-cache": "distcc"
+            }))
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_install_libdir() {
+        assert_eq!(
+            CMakeBackendConfig::default().install_libdir().as_deref(),
+            Some("lib")
+        );
+
+        let config = serde_json::from_value::<CMakeBackendConfig>(json!({
+            "cmake-install-dir-override": "lib64"
+        }))
+        .unwrap();
+        assert_eq!(config.install_libdir().as_deref(), Some("lib64"));
+
+        let config = serde_json::from_value::<CMakeBackendConfig>(json!({
+            "override-install-dirs": false
+        }))
+        .unwrap();
+        assert_eq!(config.install_libdir(), None);
+    }
+
+    #[test]
+    fn test_install_rpath() {
+        let config = CMakeBackendConfig::default();
+        assert_eq!(
+            config.install_rpath(Platform::Linux64).as_deref(),
+            Some("$ORIGIN/../lib")
+        );
+        assert_eq!(
+            config.install_rpath(Platform::OsxArm64).as_deref(),
+            Some("@loader_path/../lib")
+        );
+        assert_eq!(config.install_rpath(Platform::Win64), None);
+
+        let config = CMakeBackendConfig {
+            custom_rpath: Some("$ORIGIN/../lib/mypkg".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.install_rpath(Platform::Linux64).as_deref(),
+            Some("$ORIGIN/../lib/mypkg")
+        );
+
+        let config = CMakeBackendConfig {
+            disable_rpath: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(config.install_rpath(Platform::Linux64), None);
+    }
+
+    #[test]
+    fn test_message_log_level() {
+        assert_eq!(
+            CMakeBackendConfig::default().message_log_level().unwrap(),
+            None
+        );
+
+        let config = CMakeBackendConfig {
+            cmake_log_level: Some("verbose".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.message_log_level().unwrap(),
+            Some("VERBOSE".to_string())
+        );
+
+        let config = CMakeBackendConfig {
+            cmake_log_level: Some("loud".to_string()),
+            ..Default::default()
+        };
+        let error = config.message_log_level().unwrap_err().to_string();
+        assert!(
+            error.contains("`cmake-log-level` must be one of"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_strip_debug_symbols_deserialize() {
+        let config: CMakeBackendConfig =
+            serde_json::from_value(json!({"strip-debug-symbols": "executables"})).unwrap();
+        assert_eq!(config.strip_debug_symbols, Some(StripMode::Executables));
+
+        let config: CMakeBackendConfig =
+            serde_json::from_value(json!({"strip-debug-symbols": "all"})).unwrap();
+        assert_eq!(config.strip_debug_symbols, Some(StripMode::All));
+
+        assert!(
+            serde_json::from_value::<CMakeBackendConfig>(json!({"strip-debug-symbols": "some"}))
+                .is_err()
+        );
+    }
 }
@@ -1,10 +1,14 @@
 mod build_script;
 mod config;
+mod presets;
+mod toolchain;
+mod vcpkg;
 
 use build_script::{BuildPlatform, BuildScriptContext};
-use config::CMakeBackendConfig;
+use config::{CMakeBackendConfig, CompilerCacheKind};
 use miette::IntoDiagnostic;
 use pixi_build_backend::{
+    cache::{ccache_envs, ccache_tools, sccache_envs, sccache_tools},
     generated_recipe::{DefaultMetadataProvider, GenerateRecipe, GeneratedRecipe, PythonParams},
     intermediate_backend::IntermediateBackendInstantiator,
     traits::ProjectModel,
@@ -16,10 +20,86 @@ use recipe_stage0::recipe::Script;
 use std::collections::HashSet;
 use std::path::PathBuf;
 use std::{
-    collections::{BTreeMap, BTreeSet},
+    collections::{BTreeMap, BTreeSet, HashMap},
     path::Path,
     sync::Arc,
 };
+use toolchain::CMakeToolchain;
+
+/// Build dependencies that provide the CUDA compiler.
+const CUDA_COMPILER_PACKAGES: [&str; 2] = ["cuda-compiler", "nvcc"];
+
+/// The CUDA architectures that are built for when none are configured.
+const DEFAULT_CUDA_ARCHITECTURES: &str = "all-major";
+
+/// The cmake generator that is used when none is configured. Ninja is used on
+/// every platform, instead of the platform default of cmake, so the build
+/// behaves the same on Linux, macOS and Windows.
+const DEFAULT_GENERATOR: &str = "Ninja";
+
+/// Returns the build tool a cmake generator needs in the build environment.
+fn generator_build_tool(generator: &str) -> Option<&'static str> {
+    match generator {
+        "Ninja" | "Ninja Multi-Config" => Some("ninja"),
+        "Unix Makefiles" => Some("make"),
+        _ => None,
+    }
+}
+
+/// CMake commands that download dependencies at configure time.
+const NETWORK_COMMANDS: [&str; 2] = ["FetchContent_Declare", "ExternalProject_Add"];
+
+/// Returns the commands of a `CMakeLists.txt` that download dependencies at
+/// configure time. Like cmake, commands are matched case-insensitively, and
+/// comments are ignored.
+fn find_network_commands(cmake_lists: &str) -> Vec<&'static str> {
+    let mut found = Vec::new();
+    for line in cmake_lists.lines() {
+        let code = line
+            .split('#')
+            .next()
+            .unwrap_or_default()
+            .trim_start()
+            .to_lowercase();
+        for command in NETWORK_COMMANDS {
+            let is_invoked = code
+                .strip_prefix(&command.to_lowercase())
+                .is_some_and(|rest| rest.trim_start().starts_with('('));
+            if is_invoked && !found.contains(&command) {
+                found.push(command);
+            }
+        }
+    }
+    found
+}
+
+/// Returns true if a `CMakeLists.txt` looks for OpenMP with
+/// `find_package(OpenMP)`.
+fn uses_openmp(cmake_lists: &str) -> bool {
+    cmake_lists.lines().any(|line| {
+        let code = line
+            .split('#')
+            .next()
+            .unwrap_or_default()
+            .trim_start()
+            .to_lowercase();
+        code.strip_prefix("find_package")
+            .and_then(|rest| rest.trim_start().strip_prefix('('))
+            .is_some_and(|args| args.trim_start().starts_with("openmp"))
+    })
+}
+
+/// Returns the OpenMP runtime to add to the host requirements. MSVC ships
+/// its OpenMP runtime with the Visual C++ runtime.
+fn openmp_runtime(host_platform: Platform) -> Option<&'static str> {
+    if host_platform.is_osx() {
+        Some("llvm-openmp")
+    } else if host_platform.is_linux() {
+        Some("libgomp")
+    } else {
+        None
+    }
+}
 
 #[derive(Default, Clone)]
 pub struct CMakeGenerator {}
@@ -86,14 +166,66 @@ impl GenerateRecipe for CMakeGenerator {
             variants,
         );
 
-        // add necessary build tools
-        for tool in ["cmake", "ninja"] {
+        // Make sure the requested preset exists, otherwise cmake fails with a
+        // much less helpful error during the build.
+        let preset = config
+            .cmake_preset
+            .as_deref()
+            .map(|preset| presets::find_preset(&manifest_root, preset))
+            .transpose()?;
+
+        // add necessary build tools, a preset chooses its own generator
+        let generator = match &preset {
+            // Without a generator cmake uses its platform default, which needs
+            // make outside of Windows.
+            Some(preset) => preset.generator.clone().unwrap_or_else(|| {
+                if Platform::current().is_windows() {
+                    String::new()
+                } else {
+                    String::from("Unix Makefiles")
+                }
+            }),
+            None => config
+                .generator
+                .clone()
+                .unwrap_or_else(|| DEFAULT_GENERATOR.to_string()),
+        };
+        for tool in std::iter::once("cmake").chain(generator_build_tool(&generator)) {
             let tool_name = SourcePackageName::from(tool);
             if !model_dependencies.build.contains_key(&tool_name) {
                 requirements.build.push(tool.parse().into_diagnostic()?);
             }
         }
 
+        // Add the compiler cache to the build requirements and forward its
+        // environment variables from the system as secrets, unless they are
+        // already set in the configuration.
+        let mut cache_secrets = Vec::new();
+        if let Some(compiler_cache) = config.compiler_cache {
+            let system_env_vars = std::env::vars().collect::<HashMap<String, String>>();
+            let (tools, system_cache_keys) = match compiler_cache {
+                CompilerCacheKind::Sccache => (sccache_tools(), sccache_envs(&system_env_vars)),
+                CompilerCacheKind::Ccache => (ccache_tools(), ccache_envs(&system_env_vars)),
+            };
+
+            for tool in tools {
+                if !model_dependencies
+                    .build
+                    .contains_key(&SourcePackageName::from(tool.as_str()))
+                {
+                    requirements.build.push(tool.parse().into_diagnostic()?);
+                }
+            }
+
+            cache_secrets = system_cache_keys
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|key| !config.env.contains_key(*key))
+                .map(String::from)
+                .collect();
+            cache_secrets.sort();
+        }
+
         // Check if the host platform has a host python dependency
         // This is used to determine if we need to the cmake argument for the python
         // executable
@@ -101,6 +233,85 @@ impl GenerateRecipe for CMakeGenerator {
             .host
             .contains_key(&SourcePackageName::from("python"));
 
+        // Configure cmake for the CUDA compiler if it is part of the build
+        // environment.
+        let uses_cuda = compilers.iter().any(|compiler| compiler == "cuda")
+            || CUDA_COMPILER_PACKAGES.iter().any(|name| {
+                model_dependencies
+                    .build
+                    .contains_key(&SourcePackageName::from(*name))
+            });
+        let cuda_architectures = uses_cuda.then(|| {
+            config
+                .cuda_architectures
+                .clone()
+                .unwrap_or_else(|| DEFAULT_CUDA_ARCHITECTURES.to_string())
+        });
+
+        let cmake_lists =
+            fs_err::read_to_string(manifest_root.join("CMakeLists.txt")).unwrap_or_default();
+
+        // Build with OpenMP if the project looks for it, unless configured
+        // otherwise.
+        let openmp = config
+            .enable_openmp
+            .unwrap_or_else(|| uses_openmp(&cmake_lists));
+        let missing_runtime = openmp_runtime(host_platform).filter(|runtime| {
+            openmp
+                && !model_dependencies
+                    .host
+                    .contains_key(&SourcePackageName::from(*runtime))
+        });
+        if let Some(runtime) = missing_runtime {
+            requirements.host.push(runtime.parse().into_diagnostic()?);
+        }
+
+        // Downloads at configure time fail in build environments without network
+        // access, so point them out early.
+        if !config.allow_network_downloads.is_some_and(|allow| allow) {
+            let network_commands = find_network_commands(&cmake_lists);
+            if !network_commands.is_empty() {
+                tracing::warn!(
+                    "this package fetches dependencies at configure time ({}); consider pre-vendoring or adding them to `[package.build-dependencies]`",
+                    network_commands.join(", ")
+                );
+            }
+        }
+
+        // Install the dependencies of the vcpkg manifest with the vcpkg of the
+        // build environment.
+        let vcpkg_triplet = if config.use_vcpkg.is_some_and(|use_vcpkg| use_vcpkg) {
+            if manifest_root.join(vcpkg::VCPKG_MANIFEST).is_file() {
+                if !model_dependencies
+                    .build
+                    .contains_key(&SourcePackageName::from("vcpkg"))
+                {
+                    requirements.build.push("vcpkg".parse().into_diagnostic()?);
+                }
+                let triplet = config
+                    .vcpkg_triplet
+                    .clone()
+                    .or_else(|| {
+                        vcpkg::platform_to_vcpkg_triplet(host_platform).map(String::from)
+                    })
+                    .ok_or_else(|| {
+                        miette::miette!(
+                            "there is no vcpkg triplet for {host_platform}, set `vcpkg-triplet` to build with vcpkg"
+                        )
+                    })?;
+                Some(triplet)
+            } else {
+                tracing::warn!(
+                    "`use-vcpkg` is set, but there is no `{}` in {}",
+                    vcpkg::VCPKG_MANIFEST,
+                    manifest_root.display()
+                );
+                None
+            }
+        } else {
+            None
+        };
+
         let build_script = BuildScriptContext {
             build_platform: if Platform::current().is_windows() {
                 BuildPlatform::Windows
@@ -110,13 +321,41 @@ impl GenerateRecipe for CMakeGenerator {
             source_dir: manifest_root.display().to_string(),
             extra_args: config.extra_args.clone(),
             has_host_python,
+            preset: config.cmake_preset.clone(),
+            preset_binary_dir: preset
+                .and_then(|preset| preset.binary_dir)
+                .map(|binary_dir| binary_dir.display().to_string()),
+            compiler_launcher: config
+                .compiler_cache
+                .map(|compiler_cache| compiler_cache.executable().to_string()),
+            extra_cmake_prefix_paths: config.extra_cmake_prefix_paths.clone(),
+            run_tests: config.run_tests.unwrap_or(false),
+            test_timeout_seconds: config.test_timeout_seconds,
+            per_test_output: config.per_test_output.unwrap_or(false),
+            toolchain: if config.generate_toolchain.unwrap_or(true) {
+                CMakeToolchain::for_cross_compilation(host_platform)
+            } else {
+                None
+            },
+            install_libdir: config.install_libdir(),
+            cuda_architectures,
+            install_rpath: config.install_rpath(host_platform),
+            openmp,
+            generator,
+            vcpkg_triplet,
+            install_components: config.install_components.clone(),
+            max_parallel_jobs: config.max_parallel_jobs,
+            log_level: config.message_log_level()?,
+            find_debug: config.cmake_find_debug.unwrap_or(false),
+            strip_mode: config.strip_debug_symbols.unwrap_or_default(),
+            fetchcontent_offline: config.fetchcontent_offline.unwrap_or(false),
         }
         .render();
 
         generated_recipe.recipe.build.script = Script {
             content: build_script,
             env: config.env.clone(),
-            ..Default::default()
+            secrets: cache_secrets,
         };
 
         Ok(generated_recipe)
@@ -125,7 +364,7 @@ impl GenerateRecipe for CMakeGenerator {
     fn extract_input_globs_from_build(
         &self,
         config: &Self::Config,
-        _workdir: impl AsRef<Path>,
+        _manifest_root: impl AsRef<Path>,
         _editable: bool,
     ) -> miette::Result<BTreeSet<String>> {
         Ok([
@@ -136,8 +375,20 @@ impl GenerateRecipe for CMakeGenerator {
             "**/CMakeFiles.txt",
         ]
         .iter()
+        .chain(presets::PRESET_FILES.iter())
+        .chain(
+            vcpkg::VCPKG_FILES
+                .iter()
+                .filter(|_| config.use_vcpkg.is_some_and(|use_vcpkg| use_vcpkg)),
+        )
         .map(|s: &&str| s.to_string())
         .chain(config.extra_input_globs.clone())
+        .chain(
+            config
+                .exclude_input_globs
+                .iter()
+                .map(|glob| format!("!{glob}")),
+        )
         .collect())
     }
 
@@ -249,6 +500,164 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_unknown_cmake_preset_fails() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        fs_err::write(
+            temp_dir.path().join("CMakePresets.json"),
+            r#"{ "version": 6, "configurePresets": [{ "name": "release" }] }"#,
+        )
+        .expect("Failed to write CMakePresets.json");
+
+        let generate = |preset: &str| {
+            CMakeGenerator::default().generate_recipe(
+                &project_model,
+                &CMakeBackendConfig {
+                    cmake_preset: Some(preset.to_string()),
+                    ..Default::default()
+                },
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+        };
+
+        let generated_recipe = generate("release").expect("Failed to generate recipe");
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("--preset release")
+        );
+
+        let err = generate("missing").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the cmake preset 'missing' could not be found"
+        );
+    }
+
+    #[test]
+    fn test_cmake_preset_generator_and_binary_dir() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        fs_err::write(
+            temp_dir.path().join("CMakePresets.json"),
+            r#"{
+  "version": 6,
+  "configurePresets": [
+    { "name": "release", "generator": "Unix Makefiles", "binaryDir": "${sourceDir}/out/${presetName}" }
+  ]
+}"#,
+        )
+        .expect("Failed to write CMakePresets.json");
+
+        let generated_recipe = CMakeGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &CMakeBackendConfig {
+                    cmake_preset: Some("release".to_string()),
+                    run_tests: Some(true),
+                    ..Default::default()
+                },
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert!(build.contains(&"make".to_string()), "{build:?}");
+        assert!(!build.contains(&"ninja".to_string()), "{build:?}");
+
+        let script = &generated_recipe.recipe.build.script.content;
+        let binary_dir = temp_dir.path().join("out").join("release");
+        assert!(
+            script.contains(&format!("cmake --install \"{}\"", binary_dir.display())),
+            "{script}"
+        );
+        assert!(
+            script.contains(&format!("ctest --test-dir \"{}\"", binary_dir.display())),
+            "{script}"
+        );
+        assert!(!script.contains("ninja --version"), "{script}");
+    }
+
+    #[test]
+    fn test_compiler_cache_is_added() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = temp_env::with_vars(
+            [
+                ("SCCACHE_BUCKET", Some("system-bucket")),
+                ("SCCACHE_REGION", Some("eu-west-1")),
+            ],
+            || {
+                CMakeGenerator::default()
+                    .generate_recipe(
+                        &project_model,
+                        &CMakeBackendConfig {
+                            compiler_cache: Some(CompilerCacheKind::Sccache),
+                            env: IndexMap::from([(
+                                "SCCACHE_REGION".to_string(),
+                                "us-east-1".to_string(),
+                            )]),
+                            ..Default::default()
+                        },
+                        PathBuf::from("."),
+                        Platform::Linux64,
+                        None,
+                        &HashSet::new(),
+                        vec![],
+                    )
+                    .expect("Failed to generate recipe")
+            },
+        );
+
+        assert!(
+            generated_recipe
+                .recipe
+                .requirements
+                .build
+                .iter()
+                .any(|item| item.to_string() == "sccache"),
+            "sccache should be in the build requirements"
+        );
+
+        let script = &generated_recipe.recipe.build.script;
+        assert!(
+            script
+                .content
+                .contains("-DCMAKE_CXX_COMPILER_LAUNCHER=sccache")
+        );
+        // Only the variables that are not set in the config are forwarded
+        assert_eq!(script.secrets, vec!["SCCACHE_BUCKET".to_string()]);
+    }
+
     #[test]
     fn test_env_vars_are_set() {
         let project_model = project_fixture!({
@@ -336,6 +745,290 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_cuda_is_configured_in_build_script() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+            "targets": {
+                "defaultTarget": {
+                    "buildDependencies": {
+                        "cuda-compiler": {
+                            "binary": {
+                                "version": "*"
+                            }
+                        }
+                    }
+                },
+            }
+        });
+
+        let generate = |config: &CMakeBackendConfig, project_model: &ProjectModelV1| {
+            CMakeGenerator::default()
+                .generate_recipe(
+                    project_model,
+                    config,
+                    PathBuf::from("."),
+                    Platform::Linux64,
+                    None,
+                    &HashSet::new(),
+                    vec![],
+                )
+                .expect("Failed to generate recipe")
+                .recipe
+                .build
+                .script
+                .content
+        };
+
+        let script = generate(&CMakeBackendConfig::default(), &project_model);
+        assert!(script.contains("-DCMAKE_CUDA_COMPILER="));
+        assert!(script.contains("-DCMAKE_CUDA_ARCHITECTURES=all-major"));
+        assert!(script.contains("CUDA_HOME="));
+        assert!(script.contains("CUDAHOME="));
+
+        let script = generate(
+            &CMakeBackendConfig {
+                cuda_architectures: Some("75;86".to_string()),
+                ..Default::default()
+            },
+            &project_model,
+        );
+        assert!(script.contains("-DCMAKE_CUDA_ARCHITECTURES=75;86"));
+
+        // Without the CUDA compiler cmake is not configured for CUDA.
+        let script = generate(
+            &CMakeBackendConfig::default(),
+            &project_fixture!({
+                "name": "foobar",
+                "version": "0.1.0",
+            }),
+        );
+        assert!(!script.contains("CMAKE_CUDA_COMPILER"));
+        assert!(!script.contains("CUDA_HOME"));
+    }
+
+    #[test]
+    fn test_generator_determines_build_tool() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = CMakeGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &CMakeBackendConfig {
+                    generator: Some("Unix Makefiles".to_string()),
+                    ..Default::default()
+                },
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert!(build.contains(&"make".to_string()), "{build:?}");
+        assert!(!build.contains(&"ninja".to_string()), "{build:?}");
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains(r#"-G "Unix Makefiles""#)
+        );
+    }
+
+    #[test]
+    fn test_default_generator_is_ninja() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = CMakeGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &CMakeBackendConfig::default(),
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert!(build.contains(&"ninja".to_string()), "{build:?}");
+        assert!(!build.contains(&"make".to_string()), "{build:?}");
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains(r#"-G "Ninja""#)
+        );
+    }
+
+    #[test]
+    fn test_vcpkg_manifest_is_used() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("vcpkg.json"),
+            r#"{ "dependencies": ["fmt"] }"#,
+        )
+        .unwrap();
+
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+        let config = CMakeBackendConfig {
+            use_vcpkg: Some(true),
+            ..Default::default()
+        };
+
+        let generated_recipe = CMakeGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &config,
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert!(build.contains(&"vcpkg".to_string()), "{build:?}");
+        let script = &generated_recipe.recipe.build.script.content;
+        assert!(script.contains("/scripts/buildsystems/vcpkg.cmake"));
+        assert!(script.contains("-DVCPKG_TARGET_TRIPLET=x64-linux"));
+
+        let input_globs = CMakeGenerator::default()
+            .extract_input_globs_from_build(&config, PathBuf::new(), false)
+            .unwrap();
+        assert!(input_globs.contains("vcpkg.json"));
+        assert!(input_globs.contains("vcpkg.lock"));
+    }
+
+    #[test]
+    fn test_uses_openmp() {
+        assert!(uses_openmp(
+            "project(solver)\nfind_package( OpenMP REQUIRED)\n"
+        ));
+        assert!(!uses_openmp(
+            "project(solver)\n# find_package(OpenMP)\nfind_package(BLAS)\n"
+        ));
+    }
+
+    #[test]
+    fn test_openmp_is_detected_from_cmake_lists() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("CMakeLists.txt"),
+            "project(solver)\nfind_package(OpenMP REQUIRED)\n",
+        )
+        .unwrap();
+
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+        let generate = |config: &CMakeBackendConfig| {
+            CMakeGenerator::default()
+                .generate_recipe(
+                    &project_model,
+                    config,
+                    temp_dir.path().to_path_buf(),
+                    Platform::Linux64,
+                    None,
+                    &HashSet::new(),
+                    vec![],
+                )
+                .expect("Failed to generate recipe")
+        };
+
+        let generated_recipe = generate(&CMakeBackendConfig::default());
+        let host = generated_recipe
+            .recipe
+            .requirements
+            .host
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(host, vec!["libgomp"]);
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("-DOpenMP_CXX_FLAGS=")
+        );
+
+        // Explicitly disabling OpenMP wins over the detection.
+        let generated_recipe = generate(&CMakeBackendConfig {
+            enable_openmp: Some(false),
+            ..Default::default()
+        });
+        assert!(generated_recipe.recipe.requirements.host.is_empty());
+        assert!(
+            !generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("OpenMP")
+        );
+    }
+
+    #[test]
+    fn test_find_network_commands() {
+        let cmake_lists = r#"cmake_minimum_required(VERSION 3.24)
+project(foobar)
+
+include(FetchContent)
+fetchcontent_declare(
+    googletest
+    URL https://github.com/google/googletest/archive/refs/tags/v1.14.0.zip
+)
+# ExternalProject_Add(zlib URL https://zlib.net/zlib.tar.gz)
+"#;
+        assert_eq!(
+            find_network_commands(cmake_lists),
+            vec!["FetchContent_Declare"]
+        );
+
+        assert!(
+            find_network_commands("project(foobar)\nadd_library(foobar foobar.cpp)\n").is_empty()
+        );
+    }
+
     #[test]
     fn test_cxx_is_not_added_if_gcc_is_already_present() {
         let project_model = project_fixture!({
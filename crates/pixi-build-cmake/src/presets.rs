@@ -0,0 +1,286 @@
+use std::path::{Path, PathBuf};
+
+use miette::Diagnostic;
+use serde::Deserialize;
+
+/// The preset files that cmake reads from the source directory.
+pub const PRESET_FILES: [&str; 2] = ["CMakePresets.json", "CMakeUserPresets.json"];
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+pub enum PresetError {
+    #[error("the cmake preset '{name}' was requested but no CMakePresets.json or CMakeUserPresets.json was found in {}", .source_dir.display())]
+    #[diagnostic(help("add a CMakePresets.json to the source directory or remove `cmake-preset`"))]
+    NoPresetFile { name: String, source_dir: PathBuf },
+
+    #[error("the cmake preset '{name}' could not be found")]
+    #[diagnostic(help("available configure presets: {}", .available.join(", ")))]
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+
+    #[error("failed to parse {}", .path.display())]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The subset of a cmake presets file that is needed to validate preset
+/// names and to find the build directory and generator of a preset.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PresetsFile {
+    #[serde(default)]
+    configure_presets: Vec<Preset>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Preset {
+    name: String,
+    #[serde(default)]
+    inherits: Option<Inherits>,
+    #[serde(default)]
+    generator: Option<String>,
+    #[serde(default)]
+    binary_dir: Option<String>,
+}
+
+/// The presets a preset inherits from, either a single name or a list.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Inherits {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+impl Inherits {
+    fn names(&self) -> &[String] {
+        match self {
+            Inherits::Single(name) => std::slice::from_ref(name),
+            Inherits::Multiple(names) => names,
+        }
+    }
+}
+
+/// The settings of a configure preset that the build script depends on.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConfigurePreset {
+    /// The generator of the preset. cmake uses its default generator if it is
+    /// not set.
+    pub generator: Option<String>,
+    /// The build directory of the preset, with its macros expanded and
+    /// relative to the source directory.
+    pub binary_dir: Option<PathBuf>,
+}
+
+/// Finds the configure preset called `name` in the preset files in
+/// `source_dir`.
+///
+/// Like cmake, settings that the preset does not define are taken from the
+/// presets it inherits from, in order.
+pub fn find_preset(source_dir: &Path, name: &str) -> Result<ConfigurePreset, PresetError> {
+    let mut found_preset_file = false;
+    let mut presets = Vec::new();
+
+    for file_name in PRESET_FILES {
+        let path = source_dir.join(file_name);
+        if !path.is_file() {
+            continue;
+        }
+        found_preset_file = true;
+
+        let contents = fs_err::read_to_string(&path)?;
+        let file: PresetsFile = serde_json::from_str(&contents)
+            .map_err(|source| PresetError::Parse { path, source })?;
+        presets.extend(file.configure_presets);
+    }
+
+    if !found_preset_file {
+        return Err(PresetError::NoPresetFile {
+            name: name.to_string(),
+            source_dir: source_dir.to_path_buf(),
+        });
+    }
+
+    if !presets.iter().any(|preset| preset.name == name) {
+        return Err(PresetError::NotFound {
+            name: name.to_string(),
+            available: presets.into_iter().map(|preset| preset.name).collect(),
+        });
+    }
+
+    let generator = resolve_field(&presets, name, |preset| preset.generator.as_ref(), 0);
+    let binary_dir =
+        resolve_field(&presets, name, |preset| preset.binary_dir.as_ref(), 0).map(|binary_dir| {
+            source_dir.join(expand_macros(
+                &binary_dir,
+                source_dir,
+                name,
+                generator.as_deref(),
+            ))
+        });
+
+    Ok(ConfigurePreset {
+        generator,
+        binary_dir,
+    })
+}
+
+/// Returns the value of a field of the preset `name`, or of the first preset
+/// it inherits from that defines it.
+fn resolve_field(
+    presets: &[Preset],
+    name: &str,
+    field: fn(&Preset) -> Option<&String>,
+    depth: usize,
+) -> Option<String> {
+    // cmake rejects inheritance cycles, stop instead of recursing forever.
+    if depth > presets.len() {
+        return None;
+    }
+
+    let preset = presets.iter().find(|preset| preset.name == name)?;
+    if let Some(value) = field(preset) {
+        return Some(value.clone());
+    }
+
+    preset
+        .inherits
+        .iter()
+        .flat_map(Inherits::names)
+        .find_map(|parent| resolve_field(presets, parent, field, depth + 1))
+}
+
+/// Expands the cmake preset macros that do not depend on the environment.
+fn expand_macros(
+    value: &str,
+    source_dir: &Path,
+    preset_name: &str,
+    generator: Option<&str>,
+) -> String {
+    let source_parent_dir = source_dir.parent().unwrap_or(source_dir);
+    let source_dir_name = source_dir
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    value
+        .replace("${sourceDir}", &source_dir.display().to_string())
+        .replace(
+            "${sourceParentDir}",
+            &source_parent_dir.display().to_string(),
+        )
+        .replace("${sourceDirName}", &source_dir_name)
+        .replace("${presetName}", preset_name)
+        .replace("${generator}", generator.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRESETS: &str = r#"{
+  "version": 6,
+  "configurePresets": [
+    { "name": "release", "binaryDir": "build" },
+    { "name": "debug", "binaryDir": "build" }
+  ],
+  "buildPresets": [
+    { "name": "release", "configurePreset": "release" }
+  ]
+}"#;
+
+    #[test]
+    fn test_preset_exists() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("CMakePresets.json"), PRESETS).unwrap();
+
+        let release = find_preset(temp_dir.path(), "release").unwrap();
+        assert_eq!(release.binary_dir, Some(temp_dir.path().join("build")));
+        assert_eq!(release.generator, None);
+        find_preset(temp_dir.path(), "debug").unwrap();
+    }
+
+    #[test]
+    fn test_user_preset_exists() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("CMakePresets.json"), PRESETS).unwrap();
+        fs_err::write(
+            temp_dir.path().join("CMakeUserPresets.json"),
+            r#"{ "version": 6, "configurePresets": [{ "name": "local" }] }"#,
+        )
+        .unwrap();
+
+        let local = find_preset(temp_dir.path(), "local").unwrap();
+        assert_eq!(local, ConfigurePreset::default());
+    }
+
+    #[test]
+    fn test_preset_binary_dir_macros() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("CMakePresets.json"),
+            r#"{
+  "version": 6,
+  "configurePresets": [
+    { "name": "base", "hidden": true, "generator": "Ninja", "binaryDir": "${sourceDir}/out/${presetName}" },
+    { "name": "release", "inherits": "base" },
+    { "name": "makefiles", "inherits": ["base"], "generator": "Unix Makefiles" }
+  ]
+}"#,
+        )
+        .unwrap();
+
+        let release = find_preset(temp_dir.path(), "release").unwrap();
+        assert_eq!(
+            release,
+            ConfigurePreset {
+                generator: Some(String::from("Ninja")),
+                binary_dir: Some(PathBuf::from(format!(
+                    "{}/out/release",
+                    temp_dir.path().display()
+                ))),
+            }
+        );
+
+        let makefiles = find_preset(temp_dir.path(), "makefiles").unwrap();
+        assert_eq!(makefiles.generator.as_deref(), Some("Unix Makefiles"));
+        assert_eq!(
+            makefiles.binary_dir,
+            Some(PathBuf::from(format!(
+                "{}/out/makefiles",
+                temp_dir.path().display()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_preset_not_found() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("CMakePresets.json"), PRESETS).unwrap();
+
+        let err = find_preset(temp_dir.path(), "missing").unwrap_err();
+        assert!(matches!(
+            &err,
+            PresetError::NotFound { available, .. } if available == &["release", "debug"]
+        ));
+        assert_eq!(
+            err.to_string(),
+            "the cmake preset 'missing' could not be found"
+        );
+    }
+
+    #[test]
+    fn test_missing_preset_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let err = find_preset(temp_dir.path(), "release").unwrap_err();
+        assert!(matches!(err, PresetError::NoPresetFile { .. }));
+    }
+}
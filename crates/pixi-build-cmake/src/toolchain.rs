@@ -0,0 +1,98 @@
+use rattler_conda_types::Platform;
+use serde::Serialize;
+
+/// The target system of a cmake toolchain file that is generated when
+/// cross-compiling.
+///
+/// The C and C++ compilers are taken from the `CC` and `CXX` environment
+/// variables, which the conda compiler activation points at the
+/// cross-compilers in `$BUILD_PREFIX/bin`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CMakeToolchain {
+    /// The value of `CMAKE_SYSTEM_NAME`, e.g. `Linux`.
+    pub system_name: String,
+    /// The value of `CMAKE_SYSTEM_PROCESSOR`, e.g. `aarch64`.
+    pub system_processor: String,
+}
+
+impl CMakeToolchain {
+    /// Returns the toolchain to build for `host_platform`, or `None` if the
+    /// build is not cross-compiling or cmake has no system for the platform.
+    pub fn for_cross_compilation(host_platform: Platform) -> Option<Self> {
+        if host_platform == Platform::current() {
+            return None;
+        }
+        let (system_name, system_processor) = platform_to_cmake_system(host_platform)?;
+        Some(Self {
+            system_name,
+            system_processor,
+        })
+    }
+}
+
+/// Returns the `CMAKE_SYSTEM_NAME` and `CMAKE_SYSTEM_PROCESSOR` that
+/// correspond to the given conda platform, or `None` if there are no such
+/// values.
+pub fn platform_to_cmake_system(platform: Platform) -> Option<(String, String)> {
+    let (system_name, system_processor) = match platform {
+        Platform::Linux32 => ("Linux", "i686"),
+        Platform::Linux64 => ("Linux", "x86_64"),
+        Platform::LinuxAarch64 => ("Linux", "aarch64"),
+        Platform::LinuxArmV6l => ("Linux", "armv6l"),
+        Platform::LinuxArmV7l => ("Linux", "armv7l"),
+        Platform::LinuxPpc64le => ("Linux", "ppc64le"),
+        Platform::LinuxPpc64 => ("Linux", "ppc64"),
+        Platform::LinuxS390X => ("Linux", "s390x"),
+        Platform::Osx64 => ("Darwin", "x86_64"),
+        Platform::OsxArm64 => ("Darwin", "arm64"),
+        Platform::Win32 => ("Windows", "x86"),
+        Platform::Win64 => ("Windows", "AMD64"),
+        Platform::WinArm64 => ("Windows", "ARM64"),
+        Platform::EmscriptenWasm32 => ("Emscripten", "wasm32"),
+        Platform::WasiWasm32 => ("WASI", "wasm32"),
+        _ => return None,
+    };
+    Some((system_name.to_string(), system_processor.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn system(system_name: &str, system_processor: &str) -> Option<(String, String)> {
+        Some((system_name.to_string(), system_processor.to_string()))
+    }
+
+    #[test]
+    fn test_platform_to_cmake_system() {
+        assert_eq!(
+            platform_to_cmake_system(Platform::LinuxAarch64),
+            system("Linux", "aarch64")
+        );
+        assert_eq!(
+            platform_to_cmake_system(Platform::LinuxPpc64le),
+            system("Linux", "ppc64le")
+        );
+        assert_eq!(
+            platform_to_cmake_system(Platform::OsxArm64),
+            system("Darwin", "arm64")
+        );
+        assert_eq!(
+            platform_to_cmake_system(Platform::Osx64),
+            system("Darwin", "x86_64")
+        );
+        assert_eq!(
+            platform_to_cmake_system(Platform::WinArm64),
+            system("Windows", "ARM64")
+        );
+        assert_eq!(platform_to_cmake_system(Platform::NoArch), None);
+    }
+
+    #[test]
+    fn test_no_toolchain_for_native_builds() {
+        assert_eq!(
+            CMakeToolchain::for_cross_compilation(Platform::current()),
+            None
+        );
+    }
+}
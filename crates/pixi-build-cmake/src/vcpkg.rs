@@ -0,0 +1,50 @@
+use rattler_conda_types::Platform;
+
+/// The vcpkg manifest, which declares the dependencies to install.
+pub const VCPKG_MANIFEST: &str = "vcpkg.json";
+
+/// The files of a vcpkg project that influence the build.
+pub const VCPKG_FILES: [&str; 2] = [VCPKG_MANIFEST, "vcpkg.lock"];
+
+/// Returns the vcpkg triplet that corresponds to the given conda platform, or
+/// `None` if vcpkg has no community or built-in triplet for it.
+pub fn platform_to_vcpkg_triplet(platform: Platform) -> Option<&'static str> {
+    let triplet = match platform {
+        Platform::Linux32 => "x86-linux",
+        Platform::Linux64 => "x64-linux",
+        Platform::LinuxAarch64 => "arm64-linux",
+        Platform::LinuxArmV7l => "arm-linux",
+        Platform::LinuxPpc64le => "ppc64le-linux",
+        Platform::LinuxS390X => "s390x-linux",
+        Platform::Osx64 => "x64-osx",
+        Platform::OsxArm64 => "arm64-osx",
+        Platform::Win32 => "x86-windows",
+        Platform::Win64 => "x64-windows",
+        Platform::WinArm64 => "arm64-windows",
+        Platform::EmscriptenWasm32 => "wasm32-emscripten",
+        _ => return None,
+    };
+    Some(triplet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_to_vcpkg_triplet() {
+        assert_eq!(
+            platform_to_vcpkg_triplet(Platform::Linux64),
+            Some("x64-linux")
+        );
+        assert_eq!(
+            platform_to_vcpkg_triplet(Platform::OsxArm64),
+            Some("arm64-osx")
+        );
+        assert_eq!(
+            platform_to_vcpkg_triplet(Platform::Win64),
+            Some("x64-windows")
+        );
+        assert_eq!(platform_to_vcpkg_triplet(Platform::NoArch), None);
+    }
+}
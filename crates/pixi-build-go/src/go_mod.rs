@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+
+use miette::Diagnostic;
+use pixi_build_backend::generated_recipe::MetadataProvider;
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+pub enum GoModError {
+    #[error("go.mod does not contain a `module` directive")]
+    MissingModule,
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The subset of a `go.mod` file that is relevant for building a package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoMod {
+    /// The module path, e.g. `github.com/gohugoio/hugo`.
+    pub module: String,
+    /// The minimum Go version from the `go` directive, e.g. `1.22`.
+    pub go_version: Option<String>,
+}
+
+impl GoMod {
+    /// Reads the `go.mod` file in `manifest_root`, returns `None` if it does
+    /// not exist.
+    pub fn from_manifest_root(manifest_root: &Path) -> Result<Option<Self>, GoModError> {
+        let path = manifest_root.join("go.mod");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let contents = fs_err::read_to_string(path)?;
+        Self::parse(&contents).map(Some)
+    }
+
+    /// Parses the `module` and `go` directives from the content of a
+    /// `go.mod` file.
+    pub fn parse(contents: &str) -> Result<Self, GoModError> {
+        let mut module = None;
+        let mut go_version = None;
+
+        for line in contents.lines() {
+            let line = match line.split_once("//") {
+                Some((line, _comment)) => line,
+                None => line,
+            }
+            .trim();
+
+            if let Some(value) = line.strip_prefix("module ") {
+                module = Some(value.trim().trim_matches('"').to_string());
+            } else if let Some(value) = line.strip_prefix("go ") {
+                go_version = Some(value.trim().to_string());
+            }
+        }
+
+        Ok(Self {
+            module: module.ok_or(GoModError::MissingModule)?,
+            go_version,
+        })
+    }
+
+    /// Returns the name of the module, which is the last element of the
+    /// module path without a major version suffix (e.g. `/v2`).
+    pub fn name(&self) -> &str {
+        let mut segments = self.module.rsplit('/');
+        let last = segments.next().unwrap_or(&self.module);
+        let is_major_version = last
+            .strip_prefix('v')
+            .is_some_and(|v| !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()));
+        if is_major_version {
+            segments.next().unwrap_or(last)
+        } else {
+            last
+        }
+    }
+}
+
+/// An implementation of [`MetadataProvider`] that reads metadata from a
+/// `go.mod` file.
+pub struct GoModMetadataProvider {
+    manifest_root: PathBuf,
+    go_mod: Option<Option<GoMod>>,
+}
+
+impl GoModMetadataProvider {
+    pub fn new(manifest_root: impl Into<PathBuf>) -> Self {
+        Self {
+            manifest_root: manifest_root.into(),
+            go_mod: None,
+        }
+    }
+
+    /// Returns the parsed `go.mod` file, if any.
+    pub fn go_mod(&mut self) -> Result<Option<&GoMod>, GoModError> {
+        if self.go_mod.is_none() {
+            self.go_mod = Some(GoMod::from_manifest_root(&self.manifest_root)?);
+        }
+        Ok(self.go_mod.as_ref().and_then(Option::as_ref))
+    }
+}
+
+impl MetadataProvider for GoModMetadataProvider {
+    type Error = GoModError;
+
+    /// Returns the name of the Go module.
+    fn name(&mut self) -> Result<Option<String>, Self::Error> {
+        Ok(self.go_mod()?.map(|go_mod| go_mod.name().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_go_mod() {
+        let go_mod = GoMod::parse(
+            r#"// The hugo module
+module github.com/gohugoio/hugo
+
+go 1.22.0 // minimum version
+
+require (
+	github.com/BurntSushi/locker v0.0.0-20171006230638-a6e239ea1c69
+)
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(go_mod.module, "github.com/gohugoio/hugo");
+        assert_eq!(go_mod.go_version.as_deref(), Some("1.22.0"));
+        assert_eq!(go_mod.name(), "hugo");
+    }
+
+    #[test]
+    fn test_major_version_suffix_is_not_the_name() {
+        let go_mod = GoMod::parse("module github.com/example/tool/v2\n").unwrap();
+        assert_eq!(go_mod.go_version, None);
+        assert_eq!(go_mod.name(), "tool");
+    }
+
+    #[test]
+    fn test_missing_module_directive() {
+        assert!(matches!(
+            GoMod::parse("go 1.22\n"),
+            Err(GoModError::MissingModule)
+        ));
+    }
+}
@@ -0,0 +1,378 @@
+mod build_script;
+mod config;
+mod go_mod;
+
+use build_script::{BuildPlatform, BuildScriptContext};
+use config::GoBackendConfig;
+use go_mod::GoModMetadataProvider;
+use miette::IntoDiagnostic;
+use pixi_build_backend::{
+    generated_recipe::{GenerateRecipe, GeneratedRecipe, PythonParams},
+    intermediate_backend::IntermediateBackendInstantiator,
+    traits::ProjectModel,
+};
+use pixi_build_types::{ProjectModelV1, SourcePackageName};
+use rattler_build::{NormalizedKey, recipe::variable::Variable};
+use rattler_conda_types::{ChannelUrl, Platform};
+use recipe_stage0::recipe::Script;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    sync::Arc,
+};
+
+#[derive(Default, Clone)]
+pub struct GoGenerator {}
+
+/// Directories that are not part of the module sources.
+const IGNORED_DIRS: [&str; 3] = [".pixi", ".git", "vendor"];
+
+impl GoGenerator {
+    /// Returns true if the module contains C sources, in which case cgo has to
+    /// be enabled. Like the `**/*.{c,h}` input glob, this only looks at the
+    /// sources of the module, not at the pixi environments, the git directory
+    /// or vendored dependencies.
+    fn has_c_sources(manifest_root: &Path) -> bool {
+        walkdir::WalkDir::new(manifest_root)
+            .min_depth(1)
+            .into_iter()
+            .filter_entry(|entry| {
+                !(entry.file_type().is_dir()
+                    && IGNORED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref()))
+            })
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .any(|entry| {
+                entry
+                    .path()
+                    .extension()
+                    .is_some_and(|ext| ext == "c" || ext == "h")
+            })
+    }
+}
+
+impl GenerateRecipe for GoGenerator {
+    type Config = GoBackendConfig;
+
+    fn generate_recipe(
+        &self,
+        model: &ProjectModelV1,
+        config: &Self::Config,
+        manifest_path: PathBuf,
+        host_platform: Platform,
+        _python_params: Option<PythonParams>,
+        variants: &HashSet<NormalizedKey>,
+        _channels: Vec<ChannelUrl>,
+    ) -> miette::Result<GeneratedRecipe> {
+        // Determine the manifest root, because `manifest_path` can be
+        // either a direct file path or a directory path.
+        let manifest_root = if manifest_path.is_file() {
+            manifest_path
+                .parent()
+                .ok_or_else(|| {
+                    miette::Error::msg(format!(
+                        "Manifest path {} is a file but has no parent directory.",
+                        manifest_path.display()
+                    ))
+                })?
+                .to_path_buf()
+        } else {
+            manifest_path.clone()
+        };
+
+        let mut metadata_provider = GoModMetadataProvider::new(&manifest_root);
+
+        let mut generated_recipe =
+            GeneratedRecipe::from_model(model.clone(), &mut metadata_provider).into_diagnostic()?;
+
+        let requirements = &mut generated_recipe.recipe.requirements;
+
+        let model_dependencies = model.dependencies(Some(host_platform));
+
+        // Add the go compiler, pinned to the minimum version of the go.mod file.
+        // Without a version in the go.mod the `go` variant applies.
+        let go_pkg = SourcePackageName::from("go");
+        if !model_dependencies.build.contains_key(&go_pkg) {
+            let go_requirement = match metadata_provider
+                .go_mod()?
+                .and_then(|go_mod| go_mod.go_version.as_ref())
+            {
+                Some(go_version) => format!("go >={go_version}"),
+                None => "go".to_string(),
+            };
+            requirements
+                .build
+                .push(go_requirement.parse().into_diagnostic()?);
+        }
+
+        // cgo requires a C compiler
+        let cgo_enabled = Self::has_c_sources(&manifest_root);
+        if cgo_enabled {
+            let compilers = vec!["c".to_string()];
+            pixi_build_backend::compilers::add_compilers_to_requirements(
+                &compilers,
+                &mut requirements.build,
+                &model_dependencies,
+                &host_platform,
+            );
+            pixi_build_backend::compilers::add_stdlib_to_requirements(
+                &compilers,
+                &mut requirements.build,
+                variants,
+            );
+        }
+
+        let build_script = BuildScriptContext {
+            build_platform: if Platform::current().is_windows() {
+                BuildPlatform::Windows
+            } else {
+                BuildPlatform::Unix
+            },
+            source_dir: manifest_root.display().to_string(),
+            extra_build_args: config.extra_build_args.clone(),
+            install_path: config.install_path().to_string(),
+            cgo_enabled,
+        }
+        .render();
+
+        generated_recipe.recipe.build.script = Script {
+            content: build_script,
+            env: config.env.clone(),
+            ..Default::default()
+        };
+
+        // The go.mod influences the name and the go requirement.
+        if metadata_provider.go_mod()?.is_some() {
+            generated_recipe
+                .metadata_input_globs
+                .insert("go.mod".to_string());
+        }
+
+        Ok(generated_recipe)
+    }
+
+    fn extract_input_globs_from_build(
+        &self,
+        config: &Self::Config,
+        _manifest_root: impl AsRef<Path>,
+        _editable: bool,
+    ) -> miette::Result<BTreeSet<String>> {
+        Ok(["**/*.go", "go.mod", "go.sum", "**/*.{c,h}"]
+            .iter()
+            .map(|s: &&str| s.to_string())
+            .chain(config.extra_input_globs.clone())
+            .chain(
+                config
+                    .exclude_input_globs
+                    .iter()
+                    .map(|glob| format!("!{glob}")),
+            )
+            .collect())
+    }
+
+    fn default_variants(
+        &self,
+        host_platform: Platform,
+    ) -> miette::Result<BTreeMap<NormalizedKey, Vec<Variable>>> {
+        let mut variants = BTreeMap::new();
+
+        if host_platform.is_windows() {
+            // cgo cannot drive MSVC, it needs a gcc compatible compiler that
+            // targets mingw.
+            variants.insert(NormalizedKey::from("c_compiler"), vec!["m2w64_c".into()]);
+        }
+
+        Ok(variants)
+    }
+}
+
+#[tokio::main]
+pub async fn main() {
+    if let Err(err) = pixi_build_backend::cli::main(|log| {
+        IntermediateBackendInstantiator::<GoGenerator>::new(log, Arc::default())
+    })
+    .await
+    {
+        eprintln!("{err:?}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    #[macro_export]
+    macro_rules! project_fixture {
+        ($($json:tt)+) => {
+            serde_json::from_value::<ProjectModelV1>(
+                serde_json::json!($($json)+)
+            ).expect("Failed to create TestProjectModel from JSON fixture.")
+        };
+    }
+
+    fn build_requirements(generated_recipe: &GeneratedRecipe) -> Vec<String> {
+        generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect()
+    }
+
+    fn generate(manifest_root: &Path, config: &GoBackendConfig) -> GeneratedRecipe {
+        let project_model = project_fixture!({
+            "version": "0.1.0",
+        });
+
+        GoGenerator::default()
+            .generate_recipe(
+                &project_model,
+                config,
+                manifest_root.to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe")
+    }
+
+    #[test]
+    fn test_input_globs_includes_extra_globs() {
+        let config = GoBackendConfig {
+            extra_input_globs: vec!["custom/*.go".to_string()],
+            ..Default::default()
+        };
+
+        let generator = GoGenerator::default();
+
+        let result = generator.extract_input_globs_from_build(&config, PathBuf::new(), false);
+
+        insta::assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_go_mod_determines_name_and_go_version() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("go.mod"),
+            "module github.com/example/tool/v2\n\ngo 1.22\n",
+        )
+        .unwrap();
+        fs_err::write(temp_dir.path().join("main.go"), "package main\n").unwrap();
+
+        let generated_recipe = generate(temp_dir.path(), &GoBackendConfig::default());
+
+        assert_eq!(generated_recipe.recipe.package.name.to_string(), "tool");
+        assert_eq!(build_requirements(&generated_recipe), vec!["go >=1.22"]);
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("CGO_ENABLED=0")
+        );
+        assert!(generated_recipe.metadata_input_globs.contains("go.mod"));
+    }
+
+    #[test]
+    fn test_cgo_is_enabled_for_c_sources() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("go.mod"), "module example.com/cgo\n").unwrap();
+        fs_err::write(temp_dir.path().join("bridge.h"), "int answer();\n").unwrap();
+
+        let generated_recipe = generate(temp_dir.path(), &GoBackendConfig::default());
+
+        let build_reqs = build_requirements(&generated_recipe);
+        assert!(build_reqs.contains(&"go".to_string()));
+        assert!(build_reqs.contains(&"${{ compiler('c') }}".to_string()));
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("CGO_ENABLED=1")
+        );
+    }
+
+    #[test]
+    fn test_cgo_ignores_environments_and_vendored_sources() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("go.mod"), "module example.com/app\n").unwrap();
+        fs_err::write(temp_dir.path().join("main.go"), "package main\n").unwrap();
+        for dir in [
+            ".pixi/envs/default/include",
+            "vendor/example.com/lib",
+            ".git",
+        ] {
+            let dir = temp_dir.path().join(dir);
+            fs_err::create_dir_all(&dir).unwrap();
+            fs_err::write(dir.join("header.h"), "int answer();\n").unwrap();
+        }
+
+        let generated_recipe = generate(temp_dir.path(), &GoBackendConfig::default());
+
+        assert_eq!(build_requirements(&generated_recipe), vec!["go"]);
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("CGO_ENABLED=0")
+        );
+    }
+
+    #[test]
+    fn test_default_variants_use_a_cgo_compiler_on_windows() {
+        let variants = GoGenerator::default()
+            .default_variants(Platform::Win64)
+            .unwrap();
+        let c_compilers = variants[&NormalizedKey::from("c_compiler")]
+            .iter()
+            .map(Variable::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(c_compilers, vec!["m2w64_c"]);
+
+        let variants = GoGenerator::default()
+            .default_variants(Platform::Linux64)
+            .unwrap();
+        assert!(variants.is_empty());
+    }
+
+    #[test]
+    fn test_env_vars_and_install_path_are_set() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("go.mod"), "module example.com/app\n").unwrap();
+
+        let env = IndexMap::from([("GOFLAGS".to_string(), "-mod=vendor".to_string())]);
+        let generated_recipe = generate(
+            temp_dir.path(),
+            &GoBackendConfig {
+                env: env.clone(),
+                install_path: Some("./cmd/app".to_string()),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(generated_recipe.recipe.build.script.env, env);
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("./cmd/app")
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use miette::Diagnostic;
+use pixi_build_backend::generated_recipe::MetadataProvider;
+use rattler_conda_types::{ParseVersionError, Version};
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+pub enum CabalFileError {
+    #[error("failed to parse version from {0}, {1}")]
+    ParseVersion(String, ParseVersionError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The subset of the package description in a `.cabal` file that is relevant
+/// for building a package.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CabalFile {
+    /// The name of the package, e.g. `ShellCheck`.
+    pub name: Option<String>,
+    /// The version of the package.
+    pub version: Option<String>,
+    /// The one line description of the package.
+    pub synopsis: Option<String>,
+    /// The SPDX license of the package.
+    pub license: Option<String>,
+    /// The homepage of the package.
+    pub homepage: Option<String>,
+}
+
+impl CabalFile {
+    /// Parses the top-level fields of a `.cabal` file. The fields of the
+    /// library, executable and test sections are ignored.
+    pub fn parse(contents: &str) -> Self {
+        let mut cabal_file = Self::default();
+        for line in contents.lines() {
+            // Fields of a section and continuation lines are indented.
+            if line.starts_with(char::is_whitespace) || line.trim_start().starts_with("--") {
+                continue;
+            }
+            let Some((field, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            let value = Some(value.to_string());
+            match field.trim().to_lowercase().as_str() {
+                "name" => cabal_file.name = value,
+                "version" => cabal_file.version = value,
+                "synopsis" => cabal_file.synopsis = value,
+                "license" => cabal_file.license = value,
+                "homepage" => cabal_file.homepage = value,
+                _ => {}
+            }
+        }
+        cabal_file
+    }
+
+    /// Returns the path of the `.cabal` file in `manifest_root`, if any. If
+    /// there are multiple, the first one in alphabetical order is returned.
+    pub fn find(manifest_root: &Path) -> Result<Option<PathBuf>, CabalFileError> {
+        let mut cabal_files = fs_err::read_dir(manifest_root)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "cabal"))
+            .collect::<Vec<_>>();
+        cabal_files.sort();
+        Ok(cabal_files.into_iter().next())
+    }
+}
+
+/// An implementation of [`MetadataProvider`] that reads metadata from the
+/// `.cabal` file of a Haskell package.
+pub struct CabalMetadataProvider {
+    manifest_root: PathBuf,
+    cabal_file: Option<Option<(PathBuf, CabalFile)>>,
+}
+
+impl CabalMetadataProvider {
+    pub fn new(manifest_root: impl Into<PathBuf>) -> Self {
+        Self {
+            manifest_root: manifest_root.into(),
+            cabal_file: None,
+        }
+    }
+
+    /// Returns the path and the contents of the `.cabal` file, if any.
+    pub fn cabal_file(&mut self) -> Result<Option<&(PathBuf, CabalFile)>, CabalFileError> {
+        if self.cabal_file.is_none() {
+            let cabal_file = match CabalFile::find(&self.manifest_root)? {
+                Some(path) => {
+                    let contents = fs_err::read_to_string(&path)?;
+                    Some((path, CabalFile::parse(&contents)))
+                }
+                None => None,
+            };
+            self.cabal_file = Some(cabal_file);
+        }
+        Ok(self.cabal_file.as_ref().and_then(Option::as_ref))
+    }
+
+    fn field(
+        &mut self,
+        field: impl Fn(&CabalFile) -> Option<&String>,
+    ) -> Result<Option<String>, CabalFileError> {
+        Ok(self
+            .cabal_file()?
+            .and_then(|(_, cabal_file)| field(cabal_file).cloned()))
+    }
+}
+
+impl MetadataProvider for CabalMetadataProvider {
+    type Error = CabalFileError;
+
+    /// Returns the name of the Haskell package.
+    fn name(&mut self) -> Result<Option<String>, Self::Error> {
+        self.field(|cabal_file| cabal_file.name.as_ref())
+    }
+
+    /// Returns the version of the Haskell package.
+    fn version(&mut self) -> Result<Option<Version>, Self::Error> {
+        let Some((path, cabal_file)) = self.cabal_file()? else {
+            return Ok(None);
+        };
+        let Some(version) = &cabal_file.version else {
+            return Ok(None);
+        };
+        Ok(Some(Version::from_str(version).map_err(|err| {
+            CabalFileError::ParseVersion(path.display().to_string(), err)
+        })?))
+    }
+
+    /// Returns the homepage of the Haskell package.
+    fn homepage(&mut self) -> Result<Option<String>, Self::Error> {
+        self.field(|cabal_file| cabal_file.homepage.as_ref())
+    }
+
+    /// Returns the license of the Haskell package.
+    fn license(&mut self) -> Result<Option<String>, Self::Error> {
+        self.field(|cabal_file| cabal_file.license.as_ref())
+    }
+
+    /// Returns the synopsis of the Haskell package.
+    fn summary(&mut self) -> Result<Option<String>, Self::Error> {
+        self.field(|cabal_file| cabal_file.synopsis.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cabal_file() {
+        let cabal_file = CabalFile::parse(
+            r#"cabal-version:      2.4
+-- A comment
+Name:               ShellCheck
+version:            0.10.0
+synopsis:           Shell script analysis tool
+license:            GPL-3.0-only
+homepage:           https://www.shellcheck.net/
+
+library
+    exposed-modules: ShellCheck.Checker
+    build-depends:
+        base >= 4.8 && < 5,
+        containers
+    version: 1.0
+
+executable shellcheck
+    main-is: shellcheck.hs
+"#,
+        );
+
+        assert_eq!(
+            cabal_file,
+            CabalFile {
+                name: Some("ShellCheck".to_string()),
+                version: Some("0.10.0".to_string()),
+                synopsis: Some("Shell script analysis tool".to_string()),
+                license: Some("GPL-3.0-only".to_string()),
+                homepage: Some("https://www.shellcheck.net/".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_metadata_provider() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("example.cabal"),
+            "name: example\nversion: 0.1.0.0\n",
+        )
+        .unwrap();
+
+        let mut provider = CabalMetadataProvider::new(temp_dir.path());
+        assert_eq!(provider.name().unwrap().as_deref(), Some("example"));
+        assert_eq!(
+            provider.version().unwrap(),
+            Some(Version::from_str("0.1.0.0").unwrap())
+        );
+        assert_eq!(provider.summary().unwrap(), None);
+    }
+
+    #[test]
+    fn test_no_cabal_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let mut provider = CabalMetadataProvider::new(temp_dir.path());
+        assert_eq!(provider.name().unwrap(), None);
+        assert_eq!(provider.version().unwrap(), None);
+    }
+}
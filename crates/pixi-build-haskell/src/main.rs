@@ -0,0 +1,286 @@
+mod build_script;
+mod cabal;
+mod config;
+
+use build_script::{BuildPlatform, BuildScriptContext};
+use cabal::CabalMetadataProvider;
+use config::HaskellBackendConfig;
+use miette::IntoDiagnostic;
+use pixi_build_backend::{
+    generated_recipe::{GenerateRecipe, GeneratedRecipe, PythonParams},
+    intermediate_backend::IntermediateBackendInstantiator,
+    traits::ProjectModel,
+};
+use pixi_build_types::{ProjectModelV1, SourcePackageName};
+use rattler_build::{NormalizedKey, recipe::variable::Variable};
+use rattler_conda_types::{ChannelUrl, Platform};
+use recipe_stage0::recipe::Script;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    sync::Arc,
+};
+
+#[derive(Default, Clone)]
+pub struct HaskellGenerator {}
+
+impl GenerateRecipe for HaskellGenerator {
+    type Config = HaskellBackendConfig;
+
+    fn generate_recipe(
+        &self,
+        model: &ProjectModelV1,
+        config: &Self::Config,
+        manifest_path: PathBuf,
+        host_platform: Platform,
+        _python_params: Option<PythonParams>,
+        variants: &HashSet<NormalizedKey>,
+        _channels: Vec<ChannelUrl>,
+    ) -> miette::Result<GeneratedRecipe> {
+        // Determine the manifest root, because `manifest_path` can be
+        // either a direct file path or a directory path.
+        let manifest_root = if manifest_path.is_file() {
+            manifest_path
+                .parent()
+                .ok_or_else(|| {
+                    miette::Error::msg(format!(
+                        "Manifest path {} is a file but has no parent directory.",
+                        manifest_path.display()
+                    ))
+                })?
+                .to_path_buf()
+        } else {
+            manifest_path.clone()
+        };
+
+        let mut metadata_provider = CabalMetadataProvider::new(&manifest_root);
+        let Some((cabal_path, _)) = metadata_provider.cabal_file()? else {
+            miette::bail!("no `.cabal` file found in {}", manifest_root.display());
+        };
+        let cabal_path = cabal_path.clone();
+
+        let mut generated_recipe =
+            GeneratedRecipe::from_model(model.clone(), &mut metadata_provider).into_diagnostic()?;
+
+        let requirements = &mut generated_recipe.recipe.requirements;
+
+        let model_dependencies = model.dependencies(Some(host_platform));
+
+        // GHC links with the C compiler.
+        let compilers = config
+            .compilers
+            .clone()
+            .unwrap_or_else(|| vec!["c".to_string()]);
+        pixi_build_backend::compilers::add_compilers_to_requirements(
+            &compilers,
+            &mut requirements.build,
+            &model_dependencies,
+            &host_platform,
+        );
+        pixi_build_backend::compilers::add_stdlib_to_requirements(
+            &compilers,
+            &mut requirements.build,
+            variants,
+        );
+
+        // The package is built with cabal, using the configured ghc version.
+        let build_tools = [
+            (
+                "ghc",
+                config
+                    .ghc_version
+                    .as_ref()
+                    .map_or_else(|| "ghc".to_string(), |version| format!("ghc {version}")),
+            ),
+            ("cabal-install", "cabal-install".to_string()),
+        ];
+        for (name, spec) in build_tools {
+            if !model_dependencies
+                .build
+                .contains_key(&SourcePackageName::from(name))
+            {
+                requirements.build.push(spec.parse().into_diagnostic()?);
+            }
+        }
+
+        let build_script = BuildScriptContext {
+            build_platform: if Platform::current().is_windows() {
+                BuildPlatform::Windows
+            } else {
+                BuildPlatform::Unix
+            },
+            source_dir: manifest_root.display().to_string(),
+            cabal_flags: config.cabal_flags.clone(),
+        }
+        .render();
+
+        generated_recipe.recipe.build.script = Script {
+            content: build_script,
+            env: config.env.clone(),
+            ..Default::default()
+        };
+
+        // The .cabal file determines the name and version of the package.
+        if let Some(file_name) = cabal_path.file_name() {
+            generated_recipe
+                .metadata_input_globs
+                .insert(file_name.to_string_lossy().into_owned());
+        }
+
+        Ok(generated_recipe)
+    }
+
+    fn extract_input_globs_from_build(
+        &self,
+        config: &Self::Config,
+        _manifest_root: impl AsRef<Path>,
+        _editable: bool,
+    ) -> miette::Result<BTreeSet<String>> {
+        Ok([
+            "*.cabal",
+            "cabal.project",
+            "cabal.project.freeze",
+            "**/*.hs",
+            "**/*.lhs",
+        ]
+        .iter()
+        .map(|s: &&str| s.to_string())
+        .chain(config.extra_input_globs.clone())
+        .chain(
+            config
+                .exclude_input_globs
+                .iter()
+                .map(|glob| format!("!{glob}")),
+        )
+        .collect())
+    }
+
+    fn default_variants(
+        &self,
+        _host_platform: Platform,
+    ) -> miette::Result<BTreeMap<NormalizedKey, Vec<Variable>>> {
+        Ok(BTreeMap::new())
+    }
+}
+
+#[tokio::main]
+pub async fn main() {
+    if let Err(err) = pixi_build_backend::cli::main(|log| {
+        IntermediateBackendInstantiator::<HaskellGenerator>::new(log, Arc::default())
+    })
+    .await
+    {
+        eprintln!("{err:?}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    #[macro_export]
+    macro_rules! project_fixture {
+        ($($json:tt)+) => {
+            serde_json::from_value::<ProjectModelV1>(
+                serde_json::json!($($json)+)
+            ).expect("Failed to create TestProjectModel from JSON fixture.")
+        };
+    }
+
+    fn build_requirements(generated_recipe: &GeneratedRecipe) -> Vec<String> {
+        generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect()
+    }
+
+    fn generate(
+        manifest_root: &Path,
+        config: &HaskellBackendConfig,
+    ) -> miette::Result<GeneratedRecipe> {
+        let project_model = project_fixture!({});
+
+        HaskellGenerator::default().generate_recipe(
+            &project_model,
+            config,
+            manifest_root.to_path_buf(),
+            Platform::Linux64,
+            None,
+            &HashSet::new(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn test_input_globs_includes_extra_globs() {
+        let config = HaskellBackendConfig {
+            extra_input_globs: vec!["data/**".to_string()],
+            ..Default::default()
+        };
+
+        let generator = HaskellGenerator::default();
+
+        let result = generator.extract_input_globs_from_build(&config, PathBuf::new(), false);
+
+        insta::assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_cabal_file_determines_name_and_version() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("hello.cabal"),
+            "cabal-version: 2.4\nname: hello\nversion: 0.1.0.0\n\nexecutable hello\n    main-is: Main.hs\n",
+        )
+        .unwrap();
+
+        let env = IndexMap::from([("LANG".to_string(), "C.UTF-8".to_string())]);
+        let generated_recipe = generate(
+            temp_dir.path(),
+            &HaskellBackendConfig {
+                ghc_version: Some("9.6.*".to_string()),
+                env: env.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(generated_recipe.recipe.package.name.to_string(), "hello");
+        assert_eq!(
+            generated_recipe.recipe.package.version.to_string(),
+            "0.1.0.0"
+        );
+        assert_eq!(
+            build_requirements(&generated_recipe),
+            vec!["${{ compiler('c') }}", "ghc 9.6.*", "cabal-install"]
+        );
+        let script = &generated_recipe.recipe.build.script;
+        assert_eq!(script.env, env);
+        assert!(script.content.contains("cabal v2-install"));
+        assert!(
+            generated_recipe
+                .metadata_input_globs
+                .contains("hello.cabal")
+        );
+    }
+
+    #[test]
+    fn test_missing_cabal_file_is_an_error() {
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let Err(error) = generate(temp_dir.path(), &HaskellBackendConfig::default()) else {
+            panic!("generating a recipe without a .cabal file should fail");
+        };
+        assert!(error.to_string().contains("no `.cabal` file found"));
+    }
+}
@@ -0,0 +1,56 @@
+use minijinja::Environment;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct BuildScriptContext {
+    pub build_platform: BuildPlatform,
+    /// The directory that contains the `Project.toml`.
+    pub source_dir: String,
+    /// The name of the package, the sources are installed into
+    /// `share/julia/dev/<package_name>` of the prefix.
+    pub package_name: String,
+}
+
+#[derive(Copy, Clone, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(test, derive(strum::Display))]
+#[cfg_attr(test, strum(serialize_all = "snake_case"))]
+pub enum BuildPlatform {
+    Windows,
+    Unix,
+}
+
+impl BuildScriptContext {
+    pub fn render(&self) -> String {
+        let env = Environment::new();
+        let template = env
+            .template_from_str(include_str!("build_script.j2"))
+            .unwrap();
+        template.render(self).unwrap().trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::*;
+
+    use super::*;
+
+    #[rstest]
+    fn test_build_script(
+        #[values(BuildPlatform::Windows, BuildPlatform::Unix)] build_platform: BuildPlatform,
+    ) {
+        let context = BuildScriptContext {
+            build_platform,
+            source_dir: String::from("my-prefix-dir"),
+            package_name: String::from("Example"),
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(build_platform.to_string());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+}
@@ -0,0 +1,188 @@
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
+use pixi_build_backend::generated_recipe::BackendConfig;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct JuliaBackendConfig {
+    /// The number of threads Julia uses during the build
+    /// (`JULIA_NUM_THREADS`)
+    pub num_threads: Option<usize>,
+    /// Environment Variables
+    #[serde(default)]
+    pub env: IndexMap<String, String>,
+    /// Deprecated. Setting this has no effect; debug data is always written to
+    /// the `debug` subdirectory of the work directory.
+    #[serde(alias = "debug_dir")]
+    pub debug_dir: Option<PathBuf>,
+    /// Extra input globs to include in addition to the default ones
+    #[serde(default)]
+    pub extra_input_globs: Vec<String>,
+    /// Globs of input files to exclude from the default and extra input
+    /// globs, e.g. `docs/build/**`. Uses the same glob syntax as the
+    /// input globs.
+    #[serde(default)]
+    pub exclude_input_globs: Vec<String>,
+}
+
+impl JuliaBackendConfig {
+    /// Returns the environment variables of the build script. An explicit
+    /// `JULIA_NUM_THREADS` in `env` takes precedence over `num_threads`.
+    pub fn script_env(&self) -> IndexMap<String, String> {
+        let mut env = IndexMap::new();
+        if let Some(num_threads) = self.num_threads {
+            env.insert("JULIA_NUM_THREADS".to_string(), num_threads.to_string());
+        }
+        env.extend(self.env.clone());
+        env
+    }
+}
+
+impl BackendConfig for JuliaBackendConfig {
+    fn debug_dir(&self) -> Option<&Path> {
+        self.debug_dir.as_deref()
+    }
+
+    /// Merge this configuration with a target-specific configuration.
+    /// Target-specific values override base values using the following rules:
+    /// - num_threads: Platform-specific takes precedence
+    /// - env: Platform env vars override base, others merge
+    /// - debug_dir: Not allowed to have target specific value
+    /// - extra_input_globs: Platform-specific completely replaces base
+    /// - exclude_input_globs: Platform-specific completely replaces base
+    fn merge_with_target_config(&self, target_config: &Self) -> miette::Result<Self> {
+        if target_config.debug_dir.is_some() {
+            miette::bail!("`debug_dir` cannot have a target specific value");
+        }
+
+        Ok(Self {
+            num_threads: target_config.num_threads.or(self.num_threads),
+            env: {
+                let mut merged_env = self.env.clone();
+                merged_env.extend(target_config.env.clone());
+                merged_env
+            },
+            debug_dir: self.debug_dir.clone(),
+            extra_input_globs: if target_config.extra_input_globs.is_empty() {
+                self.extra_input_globs.clone()
+            } else {
+                target_config.extra_input_globs.clone()
+            },
+            exclude_input_globs: if target_config.exclude_input_globs.is_empty() {
+                self.exclude_input_globs.clone()
+            } else {
+                target_config.exclude_input_globs.clone()
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pixi_build_backend::generated_recipe::BackendConfig;
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    use super::JuliaBackendConfig;
+
+    #[test]
+    fn test_ensure_deseralize_from_empty() {
+        let json_data = json!({});
+        serde_json::from_value::<JuliaBackendConfig>(json_data).unwrap();
+    }
+
+    #[test]
+    fn test_merge_with_target_config() {
+        let mut base_env = indexmap::IndexMap::new();
+        base_env.insert("BASE_VAR".to_string(), "base_value".to_string());
+        base_env.insert("SHARED_VAR".to_string(), "base_shared".to_string());
+
+        let base_config = JuliaBackendConfig {
+            num_threads: Some(4),
+            env: base_env,
+            debug_dir: Some(PathBuf::from("/base/debug")),
+            extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
+        };
+
+        let mut target_env = indexmap::IndexMap::new();
+        target_env.insert("TARGET_VAR".to_string(), "target_value".to_string());
+        target_env.insert("SHARED_VAR".to_string(), "target_shared".to_string());
+
+        let target_config = JuliaBackendConfig {
+            num_threads: None,
+            env: target_env,
+            debug_dir: None,
+            extra_input_globs: vec!["*.target".to_string()],
+            exclude_input_globs: vec![],
+        };
+
+        let merged = base_config
+            .merge_with_target_config(&target_config)
+            .unwrap();
+
+        // num_threads should keep the base value if the target does not set it
+        assert_eq!(merged.num_threads, Some(4));
+
+        // env should merge with target taking precedence
+        assert_eq!(merged.env.get("BASE_VAR"), Some(&"base_value".to_string()));
+        assert_eq!(
+            merged.env.get("TARGET_VAR"),
+            Some(&"target_value".to_string())
+        );
+        assert_eq!(
+            merged.env.get("SHARED_VAR"),
+            Some(&"target_shared".to_string())
+        );
+
+        // debug_dir should use base value
+        assert_eq!(merged.debug_dir, Some(PathBuf::from("/base/debug")));
+
+        // extra_input_globs should be completely overridden
+        assert_eq!(merged.extra_input_globs, vec!["*.target".to_string()]);
+    }
+
+    #[test]
+    fn test_merge_target_debug_dir_error() {
+        let base_config = JuliaBackendConfig {
+            debug_dir: Some(PathBuf::from("/base/debug")),
+            ..Default::default()
+        };
+
+        let target_config = JuliaBackendConfig {
+            debug_dir: Some(PathBuf::from("/target/debug")),
+            ..Default::default()
+        };
+
+        let result = base_config.merge_with_target_config(&target_config);
+        assert!(result.is_err());
+        let error_msg = result.unwrap_err().to_string();
+        assert!(error_msg.contains("`debug_dir` cannot have a target specific value"));
+    }
+
+    #[test]
+    fn test_num_threads_sets_script_env() {
+        let config = JuliaBackendConfig {
+            num_threads: Some(8),
+            ..Default::default()
+        };
+        assert_eq!(
+            config.script_env().get("JULIA_NUM_THREADS"),
+            Some(&"8".to_string())
+        );
+
+        let mut env = indexmap::IndexMap::new();
+        env.insert("JULIA_NUM_THREADS".to_string(), "auto".to_string());
+        let config = JuliaBackendConfig {
+            num_threads: Some(8),
+            env,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.script_env().get("JULIA_NUM_THREADS"),
+            Some(&"auto".to_string())
+        );
+    }
+}
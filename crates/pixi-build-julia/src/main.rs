@@ -0,0 +1,296 @@
+mod build_script;
+mod config;
+mod project_toml;
+
+use build_script::{BuildPlatform, BuildScriptContext};
+use config::JuliaBackendConfig;
+use miette::IntoDiagnostic;
+use pixi_build_backend::{
+    generated_recipe::{GenerateRecipe, GeneratedRecipe, PythonParams},
+    intermediate_backend::IntermediateBackendInstantiator,
+    traits::ProjectModel,
+};
+use pixi_build_types::{ProjectModelV1, SourcePackageName};
+use project_toml::ProjectTomlMetadataProvider;
+use rattler_build::{NormalizedKey, recipe::variable::Variable};
+use rattler_conda_types::{ChannelUrl, Platform};
+use recipe_stage0::recipe::Script;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    sync::Arc,
+};
+
+/// The Julia version that is used when no `julia` variant is configured.
+const DEFAULT_JULIA_VERSION: &str = "1.11";
+
+#[derive(Default, Clone)]
+pub struct JuliaGenerator {}
+
+impl GenerateRecipe for JuliaGenerator {
+    type Config = JuliaBackendConfig;
+
+    fn generate_recipe(
+        &self,
+        model: &ProjectModelV1,
+        config: &Self::Config,
+        manifest_path: PathBuf,
+        host_platform: Platform,
+        _python_params: Option<PythonParams>,
+        _variants: &HashSet<NormalizedKey>,
+        _channels: Vec<ChannelUrl>,
+    ) -> miette::Result<GeneratedRecipe> {
+        // Determine the manifest root, because `manifest_path` can be
+        // either a direct file path or a directory path.
+        let manifest_root = if manifest_path.is_file() {
+            manifest_path
+                .parent()
+                .ok_or_else(|| {
+                    miette::Error::msg(format!(
+                        "Manifest path {} is a file but has no parent directory.",
+                        manifest_path.display()
+                    ))
+                })?
+                .to_path_buf()
+        } else {
+            manifest_path.clone()
+        };
+
+        let mut metadata_provider = ProjectTomlMetadataProvider::new(&manifest_root);
+
+        let mut generated_recipe =
+            GeneratedRecipe::from_model(model.clone(), &mut metadata_provider).into_diagnostic()?;
+
+        let requirements = &mut generated_recipe.recipe.requirements;
+
+        let model_dependencies = model.dependencies(Some(host_platform));
+
+        // Add julia, pinned to the minimum version of the `julia` compat entry
+        // of the Project.toml. Without a compat entry the `julia` variant
+        // applies.
+        let julia_pkg = SourcePackageName::from("julia");
+        if !model_dependencies.build.contains_key(&julia_pkg) {
+            let julia_requirement = match metadata_provider
+                .project_toml()?
+                .and_then(|project_toml| project_toml.min_julia_version())
+            {
+                Some(julia_version) => format!("julia >={julia_version}"),
+                None => "julia".to_string(),
+            };
+            requirements
+                .build
+                .push(julia_requirement.parse().into_diagnostic()?);
+        }
+
+        let build_script = BuildScriptContext {
+            build_platform: if Platform::current().is_windows() {
+                BuildPlatform::Windows
+            } else {
+                BuildPlatform::Unix
+            },
+            source_dir: manifest_root.display().to_string(),
+            package_name: generated_recipe.recipe.package.name.to_string(),
+        }
+        .render();
+
+        generated_recipe.recipe.build.script = Script {
+            content: build_script,
+            env: config.script_env(),
+            ..Default::default()
+        };
+
+        // The Project.toml influences the name, version and julia requirement.
+        if metadata_provider.project_toml()?.is_some() {
+            generated_recipe
+                .metadata_input_globs
+                .insert("Project.toml".to_string());
+        }
+
+        Ok(generated_recipe)
+    }
+
+    fn extract_input_globs_from_build(
+        &self,
+        config: &Self::Config,
+        _manifest_root: impl AsRef<Path>,
+        _editable: bool,
+    ) -> miette::Result<BTreeSet<String>> {
+        Ok(
+            ["Project.toml", "Manifest.toml", "**/*.jl", "deps/build.jl"]
+                .iter()
+                .map(|s: &&str| s.to_string())
+                .chain(config.extra_input_globs.clone())
+                .chain(
+                    config
+                        .exclude_input_globs
+                        .iter()
+                        .map(|glob| format!("!{glob}")),
+                )
+                .collect(),
+        )
+    }
+
+    fn default_variants(
+        &self,
+        _host_platform: Platform,
+    ) -> miette::Result<BTreeMap<NormalizedKey, Vec<Variable>>> {
+        Ok(BTreeMap::from([(
+            NormalizedKey::from("julia"),
+            vec![DEFAULT_JULIA_VERSION.into()],
+        )]))
+    }
+}
+
+#[tokio::main]
+pub async fn main() {
+    if let Err(err) = pixi_build_backend::cli::main(|log| {
+        IntermediateBackendInstantiator::<JuliaGenerator>::new(log, Arc::default())
+    })
+    .await
+    {
+        eprintln!("{err:?}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    #[macro_export]
+    macro_rules! project_fixture {
+        ($($json:tt)+) => {
+            serde_json::from_value::<ProjectModelV1>(
+                serde_json::json!($($json)+)
+            ).expect("Failed to create TestProjectModel from JSON fixture.")
+        };
+    }
+
+    fn build_requirements(generated_recipe: &GeneratedRecipe) -> Vec<String> {
+        generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect()
+    }
+
+    fn generate(manifest_root: &Path, config: &JuliaBackendConfig) -> GeneratedRecipe {
+        let project_model = project_fixture!({
+            "version": "0.1.0",
+        });
+
+        JuliaGenerator::default()
+            .generate_recipe(
+                &project_model,
+                config,
+                manifest_root.to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe")
+    }
+
+    #[test]
+    fn test_input_globs_includes_extra_globs() {
+        let config = JuliaBackendConfig {
+            extra_input_globs: vec!["custom/*.toml".to_string()],
+            ..Default::default()
+        };
+
+        let generator = JuliaGenerator::default();
+
+        let result = generator.extract_input_globs_from_build(&config, PathBuf::new(), false);
+
+        insta::assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_project_toml_determines_name_and_julia_version() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("Project.toml"),
+            r#"name = "Example"
+uuid = "7876af07-990d-54b4-ab0e-23690620f79a"
+version = "0.5.1"
+
+[compat]
+julia = "1.10"
+"#,
+        )
+        .unwrap();
+
+        let generated_recipe = generate(temp_dir.path(), &JuliaBackendConfig::default());
+
+        assert_eq!(generated_recipe.recipe.package.name.to_string(), "Example");
+        assert_eq!(build_requirements(&generated_recipe), vec!["julia >=1.10"]);
+
+        // The sources are installed as a developed package of the depot in
+        // the prefix.
+        let script = &generated_recipe.recipe.build.script.content;
+        let package_dir = if Platform::current().is_windows() {
+            r"share\julia\dev\Example"
+        } else {
+            "share/julia/dev/Example"
+        };
+        assert!(script.contains("JULIA_DEPOT_PATH"), "{script}");
+        assert!(script.contains(package_dir), "{script}");
+        assert!(script.contains("Pkg.precompile()"), "{script}");
+        assert!(
+            generated_recipe
+                .metadata_input_globs
+                .contains("Project.toml")
+        );
+    }
+
+    #[test]
+    fn test_julia_variant_applies_without_compat() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("Project.toml"),
+            "name = \"Example\"\nversion = \"0.5.1\"\n",
+        )
+        .unwrap();
+
+        let generated_recipe = generate(temp_dir.path(), &JuliaBackendConfig::default());
+        assert_eq!(build_requirements(&generated_recipe), vec!["julia"]);
+
+        let variants = JuliaGenerator::default()
+            .default_variants(Platform::Linux64)
+            .unwrap();
+        assert!(variants.contains_key(&NormalizedKey::from("julia")));
+    }
+
+    #[test]
+    fn test_num_threads_and_env_vars_are_set() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("Project.toml"), "name = \"Example\"\n").unwrap();
+
+        let env = IndexMap::from([("JULIA_PKG_OFFLINE".to_string(), "true".to_string())]);
+        let generated_recipe = generate(
+            temp_dir.path(),
+            &JuliaBackendConfig {
+                num_threads: Some(4),
+                env,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(
+            generated_recipe.recipe.build.script.env,
+            IndexMap::from([
+                ("JULIA_NUM_THREADS".to_string(), "4".to_string()),
+                ("JULIA_PKG_OFFLINE".to_string(), "true".to_string()),
+            ])
+        );
+    }
+}
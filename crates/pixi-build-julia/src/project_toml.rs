@@ -0,0 +1,177 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use miette::Diagnostic;
+use pixi_build_backend::generated_recipe::MetadataProvider;
+use rattler_conda_types::{ParseVersionError, Version};
+use serde::Deserialize;
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+pub enum ProjectTomlError {
+    #[error("failed to parse Project.toml, {0}")]
+    Parse(#[from] toml::de::Error),
+    #[error("failed to parse version from Project.toml, {0}")]
+    ParseVersion(ParseVersionError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// The subset of a Julia `Project.toml` file that is relevant for building a
+/// package.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ProjectToml {
+    /// The name of the package, e.g. `Flux`.
+    pub name: Option<String>,
+    /// The UUID that identifies the package in the Julia registries.
+    pub uuid: Option<String>,
+    /// The version of the package.
+    pub version: Option<String>,
+    /// The compatibility constraints of the dependencies, including `julia`
+    /// itself.
+    #[serde(default)]
+    pub compat: BTreeMap<String, String>,
+}
+
+impl ProjectToml {
+    /// Reads the `Project.toml` file in `manifest_root`, returns `None` if it
+    /// does not exist.
+    pub fn from_manifest_root(manifest_root: &Path) -> Result<Option<Self>, ProjectTomlError> {
+        let path = manifest_root.join("Project.toml");
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let contents = fs_err::read_to_string(path)?;
+        Ok(Some(toml::from_str(&contents)?))
+    }
+
+    /// Returns the minimum Julia version from the `julia` compat entry.
+    ///
+    /// A compat entry such as `"1.6, ^1.9"` lists multiple ranges, the lowest
+    /// lower bound is returned.
+    pub fn min_julia_version(&self) -> Option<Version> {
+        self.compat
+            .get("julia")?
+            .split(',')
+            .filter_map(|range| {
+                let version = range.trim().trim_start_matches(['^', '~', '=']).trim();
+                Version::from_str(version).ok()
+            })
+            .min()
+    }
+}
+
+/// An implementation of [`MetadataProvider`] that reads metadata from a
+/// Julia `Project.toml` file.
+pub struct ProjectTomlMetadataProvider {
+    manifest_root: PathBuf,
+    project_toml: Option<Option<ProjectToml>>,
+}
+
+impl ProjectTomlMetadataProvider {
+    pub fn new(manifest_root: impl Into<PathBuf>) -> Self {
+        Self {
+            manifest_root: manifest_root.into(),
+            project_toml: None,
+        }
+    }
+
+    /// Returns the parsed `Project.toml` file, if any.
+    pub fn project_toml(&mut self) -> Result<Option<&ProjectToml>, ProjectTomlError> {
+        if self.project_toml.is_none() {
+            self.project_toml = Some(ProjectToml::from_manifest_root(&self.manifest_root)?);
+        }
+        Ok(self.project_toml.as_ref().and_then(Option::as_ref))
+    }
+}
+
+impl MetadataProvider for ProjectTomlMetadataProvider {
+    type Error = ProjectTomlError;
+
+    /// Returns the name of the Julia package.
+    fn name(&mut self) -> Result<Option<String>, Self::Error> {
+        Ok(self
+            .project_toml()?
+            .and_then(|project_toml| project_toml.name.clone()))
+    }
+
+    /// Returns the version of the Julia package.
+    fn version(&mut self) -> Result<Option<Version>, Self::Error> {
+        let Some(version) = self
+            .project_toml()?
+            .and_then(|project_toml| project_toml.version.as_deref())
+        else {
+            return Ok(None);
+        };
+        Ok(Some(
+            Version::from_str(version).map_err(ProjectTomlError::ParseVersion)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project_toml() {
+        let project_toml: ProjectToml = toml::from_str(
+            r#"name = "Flux"
+uuid = "587475ba-b771-5e3f-ad9e-33799f191a9c"
+version = "0.14.25"
+
+[deps]
+Adapt = "79e6a3ab-5dfb-504d-930d-738a2a938a0e"
+
+[compat]
+Adapt = "4"
+julia = "1.9, 1.10"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(project_toml.name.as_deref(), Some("Flux"));
+        assert_eq!(
+            project_toml.uuid.as_deref(),
+            Some("587475ba-b771-5e3f-ad9e-33799f191a9c")
+        );
+        assert_eq!(project_toml.version.as_deref(), Some("0.14.25"));
+        assert_eq!(
+            project_toml.min_julia_version(),
+            Some(Version::from_str("1.9").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_min_julia_version_with_specifiers() {
+        let project_toml = ProjectToml {
+            compat: BTreeMap::from([("julia".to_string(), "^1.10, ~1.6".to_string())]),
+            ..Default::default()
+        };
+        assert_eq!(
+            project_toml.min_julia_version(),
+            Some(Version::from_str("1.6").unwrap())
+        );
+
+        assert_eq!(ProjectToml::default().min_julia_version(), None);
+    }
+
+    #[test]
+    fn test_metadata_provider() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("Project.toml"),
+            "name = \"Example\"\nversion = \"1.2.3\"\n",
+        )
+        .unwrap();
+
+        let mut provider = ProjectTomlMetadataProvider::new(temp_dir.path());
+        assert_eq!(provider.name().unwrap().as_deref(), Some("Example"));
+        assert_eq!(
+            provider.version().unwrap(),
+            Some(Version::from_str("1.2.3").unwrap())
+        );
+    }
+}
@@ -0,0 +1,314 @@
+mod build_script;
+mod config;
+
+use build_script::{BuildPlatform, BuildScriptContext};
+use config::MesonBackendConfig;
+use miette::IntoDiagnostic;
+use pixi_build_backend::{
+    generated_recipe::{DefaultMetadataProvider, GenerateRecipe, GeneratedRecipe, PythonParams},
+    intermediate_backend::IntermediateBackendInstantiator,
+    traits::ProjectModel,
+};
+use pixi_build_types::{ProjectModelV1, SourcePackageName};
+use rattler_build::{NormalizedKey, recipe::variable::Variable};
+use rattler_conda_types::{ChannelUrl, Platform};
+use recipe_stage0::recipe::Script;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+    sync::Arc,
+};
+
+#[derive(Default, Clone)]
+pub struct MesonGenerator {}
+
+impl GenerateRecipe for MesonGenerator {
+    type Config = MesonBackendConfig;
+
+    fn generate_recipe(
+        &self,
+        model: &ProjectModelV1,
+        config: &Self::Config,
+        manifest_path: PathBuf,
+        host_platform: Platform,
+        _python_params: Option<PythonParams>,
+        variants: &HashSet<NormalizedKey>,
+        _channels: Vec<ChannelUrl>,
+    ) -> miette::Result<GeneratedRecipe> {
+        // Determine the manifest root, because `manifest_path` can be
+        // either a direct file path or a directory path.
+        let manifest_root = if manifest_path.is_file() {
+            manifest_path
+                .parent()
+                .ok_or_else(|| {
+                    miette::Error::msg(format!(
+                        "Manifest path {} is a file but has no parent directory.",
+                        manifest_path.display()
+                    ))
+                })?
+                .to_path_buf()
+        } else {
+            manifest_path.clone()
+        };
+
+        let mut generated_recipe =
+            GeneratedRecipe::from_model(model.clone(), &mut DefaultMetadataProvider)
+                .into_diagnostic()?;
+
+        let requirements = &mut generated_recipe.recipe.requirements;
+
+        // Get the platform-specific dependencies from the project model.
+        let model_dependencies = model.dependencies(Some(host_platform));
+
+        // Get the list of compilers from config, defaulting to ["c", "cxx"] if not
+        // specified
+        let compilers = config
+            .compilers
+            .clone()
+            .unwrap_or_else(|| vec!["c".to_string(), "cxx".to_string()]);
+
+        // Add configured compilers to build requirements
+        pixi_build_backend::compilers::add_compilers_to_requirements(
+            &compilers,
+            &mut requirements.build,
+            &model_dependencies,
+            &host_platform,
+        );
+        pixi_build_backend::compilers::add_stdlib_to_requirements(
+            &compilers,
+            &mut requirements.build,
+            variants,
+        );
+
+        // add necessary build tools
+        for tool in ["meson", "ninja"] {
+            let tool_name = SourcePackageName::from(tool);
+            if !model_dependencies.build.contains_key(&tool_name) {
+                requirements.build.push(tool.parse().into_diagnostic()?);
+            }
+        }
+
+        let build_script = BuildScriptContext {
+            build_platform: if Platform::current().is_windows() {
+                BuildPlatform::Windows
+            } else {
+                BuildPlatform::Unix
+            },
+            source_dir: manifest_root.display().to_string(),
+            extra_args: config.extra_args.clone(),
+        }
+        .render();
+
+        generated_recipe.recipe.build.script = Script {
+            content: build_script,
+            env: config.env.clone(),
+            ..Default::default()
+        };
+
+        Ok(generated_recipe)
+    }
+
+    fn extract_input_globs_from_build(
+        &self,
+        config: &Self::Config,
+        _manifest_root: impl AsRef<Path>,
+        _editable: bool,
+    ) -> miette::Result<BTreeSet<String>> {
+        Ok([
+            // Source files
+            "**/*.{c,cpp,h}",
+            // Meson files
+            "**/meson.build",
+            "meson.options",
+            "meson_options.txt",
+            // Python scripts invoked by Meson
+            "**/*.py",
+        ]
+        .iter()
+        .map(|s: &&str| s.to_string())
+        .chain(config.extra_input_globs.clone())
+        .chain(
+            config
+                .exclude_input_globs
+                .iter()
+                .map(|glob| format!("!{glob}")),
+        )
+        .collect())
+    }
+
+    fn default_variants(
+        &self,
+        host_platform: Platform,
+    ) -> miette::Result<BTreeMap<NormalizedKey, Vec<Variable>>> {
+        let mut variants = BTreeMap::new();
+
+        if host_platform.is_windows() {
+            // Default to the Visual Studio 2022 compiler on Windows, same as the
+            // cmake backend.
+            variants.insert(NormalizedKey::from("c_compiler"), vec!["vs2022".into()]);
+            variants.insert(NormalizedKey::from("cxx_compiler"), vec!["vs2022".into()]);
+        }
+
+        Ok(variants)
+    }
+}
+
+#[tokio::main]
+pub async fn main() {
+    if let Err(err) = pixi_build_backend::cli::main(|log| {
+        IntermediateBackendInstantiator::<MesonGenerator>::new(log, Arc::default())
+    })
+    .await
+    {
+        eprintln!("{err:?}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use indexmap::IndexMap;
+
+    use super::*;
+
+    #[macro_export]
+    macro_rules! project_fixture {
+        ($($json:tt)+) => {
+            serde_json::from_value::<ProjectModelV1>(
+                serde_json::json!($($json)+)
+            ).expect("Failed to create TestProjectModel from JSON fixture.")
+        };
+    }
+
+    fn build_requirement_names(generated_recipe: &GeneratedRecipe) -> Vec<String> {
+        generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_input_globs_includes_extra_globs() {
+        let config = MesonBackendConfig {
+            extra_input_globs: vec!["custom/*.c".to_string()],
+            ..Default::default()
+        };
+
+        let generator = MesonGenerator::default();
+
+        let result = generator.extract_input_globs_from_build(&config, PathBuf::new(), false);
+
+        insta::assert_debug_snapshot!(result);
+    }
+
+    #[test]
+    fn test_meson_and_ninja_are_in_build_requirements() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = MesonGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &MesonBackendConfig::default(),
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build_reqs = build_requirement_names(&generated_recipe);
+        assert!(build_reqs.contains(&"meson".to_string()));
+        assert!(build_reqs.contains(&"ninja".to_string()));
+        assert!(build_reqs.contains(&"${{ compiler('c') }}".to_string()));
+        assert!(build_reqs.contains(&"${{ compiler('cxx') }}".to_string()));
+    }
+
+    #[test]
+    fn test_meson_is_not_added_if_already_present() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+            "targets": {
+                "defaultTarget": {
+                    "buildDependencies": {
+                        "meson": {
+                            "binary": {
+                                "version": ">=1.4"
+                            }
+                        }
+                    }
+                },
+            }
+        });
+
+        let generated_recipe = MesonGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &MesonBackendConfig::default(),
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let meson_count = build_requirement_names(&generated_recipe)
+            .iter()
+            .filter(|name| name.starts_with("meson"))
+            .count();
+        assert_eq!(meson_count, 1, "meson should only be listed once");
+    }
+
+    #[test]
+    fn test_env_vars_are_set() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let env = IndexMap::from([("foo".to_string(), "bar".to_string())]);
+
+        let generated_recipe = MesonGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &MesonBackendConfig {
+                    env: env.clone(),
+                    ..Default::default()
+                },
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        assert_eq!(generated_recipe.recipe.build.script.env, env);
+    }
+
+    #[test]
+    fn test_windows_default_compilers() {
+        let variants = MesonGenerator::default()
+            .default_variants(Platform::Win64)
+            .unwrap();
+        assert!(variants.contains_key(&NormalizedKey::from("c_compiler")));
+        assert!(variants.contains_key(&NormalizedKey::from("cxx_compiler")));
+
+        let variants = MesonGenerator::default()
+            .default_variants(Platform::Linux64)
+            .unwrap();
+        assert!(variants.is_empty());
+    }
+}
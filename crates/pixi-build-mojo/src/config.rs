@@ -24,6 +24,12 @@ pub struct MojoBackendConfig {
     #[serde(default)]
     pub extra_input_globs: Vec<String>,
 
+    /// Globs of input files to exclude from the default and extra input
+    /// globs, e.g. `**/*.mojopkg`. Uses the same glob syntax as the
+    /// input globs.
+    #[serde(default)]
+    pub exclude_input_globs: Vec<String>,
+
     /// Binary executables to produce.
     pub bins: Option<Vec<MojoBinConfig>>,
 
@@ -46,6 +52,7 @@ impl BackendConfig for MojoBackendConfig {
     /// - env: Platform env vars override base, others merge
     /// - debug_dir: Not allowed to have target specific value
     /// - extra_input_globs: Platform-specific completely replaces base
+    /// - exclude_input_globs: Platform-specific completely replaces base
     /// - bins: Any bins with matching not-None names will be merged,
     ///   Any set-settings on the platform specific pkg override base
     ///   Any bins found only in target_config will be kept
@@ -122,6 +129,11 @@ impl BackendConfig for MojoBackendConfig {
             } else {
                 target_config.extra_input_globs.clone()
             },
+            exclude_input_globs: if target_config.exclude_input_globs.is_empty() {
+                self.exclude_input_globs.clone()
+            } else {
+                target_config.exclude_input_globs.clone()
+            },
             bins,
             pkg,
             compilers: target_config
@@ -114,11 +114,17 @@ impl GenerateRecipe for MojoGenerator {
     fn extract_input_globs_from_build(
         &self,
         config: &Self::Config,
-        _workdir: impl AsRef<Path>,
+        _manifest_root: impl AsRef<Path>,
         _editable: bool,
     ) -> miette::Result<BTreeSet<String>> {
         Ok(Self::globs()
             .chain(config.extra_input_globs.clone())
+            .chain(
+                config
+                    .exclude_input_globs
+                    .iter()
+                    .map(|glob| format!("!{glob}")),
+            )
             .collect())
     }
 
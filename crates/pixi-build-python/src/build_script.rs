@@ -11,6 +11,23 @@ pub struct BuildScriptContext {
     pub editable: bool,
     pub extra_args: Vec<String>,
     pub manifest_root: PathBuf,
+    /// The version `setuptools-scm` should report instead of deriving it
+    /// from the git metadata, which is not available in the build.
+    pub setuptools_scm_version: Option<String>,
+    /// The extras of the package to install.
+    pub extras: Vec<String>,
+    /// Clear `PYTHONPATH` and `PYTHONSTARTUP` and disable the user
+    /// site-packages so the environment of the caller does not leak into the
+    /// build.
+    pub clean_python_env: bool,
+    /// The directory the installer caches wheels in, defaults to
+    /// `$HOME/.cache/pixi-build/pip` or `$HOME/.cache/pixi-build/uv`.
+    pub cache_dir: Option<PathBuf>,
+    /// Disable the wheel cache of the installer.
+    pub no_cache: bool,
+    /// Run `pip check` after the installation and report conflicting
+    /// dependencies without failing the build.
+    pub pip_check: bool,
 }
 
 #[derive(Default, Serialize)]
@@ -61,3 +78,233 @@ impl BuildScriptContext {
         template.render(self).unwrap().trim().to_string()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render_with_manifest_root(build_platform: BuildPlatform, installer: Installer) -> String {
+        BuildScriptContext {
+            installer,
+            build_platform,
+            editable: false,
+            extra_args: vec![],
+            manifest_root: PathBuf::from("/path with spaces/my package"),
+            setuptools_scm_version: None,
+            extras: vec![],
+            clean_python_env: false,
+            cache_dir: None,
+            no_cache: false,
+            pip_check: false,
+        }
+        .render()
+    }
+
+    #[test]
+    fn test_manifest_root_with_spaces_is_quoted() {
+        for build_platform in [BuildPlatform::Windows, BuildPlatform::Unix] {
+            for installer in [Installer::Pip, Installer::Uv] {
+                let script = render_with_manifest_root(build_platform, installer);
+                assert!(
+                    script.contains("\"/path with spaces/my package\""),
+                    "manifest root is not quoted in:\n{script}"
+                );
+                assert!(
+                    !script.contains(" /path with spaces"),
+                    "manifest root is split in:\n{script}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_python_is_quoted() {
+        let script = render_with_manifest_root(BuildPlatform::Windows, Installer::Pip);
+        assert!(script.starts_with("\"%PYTHON%\" -m pip install"));
+
+        let script = render_with_manifest_root(BuildPlatform::Unix, Installer::Uv);
+        assert!(script.starts_with("uv pip install --python \"$PYTHON\""));
+    }
+
+    #[test]
+    fn test_setuptools_scm_version_is_exported_before_install() {
+        for (build_platform, export) in [
+            (
+                BuildPlatform::Windows,
+                "SET SETUPTOOLS_SCM_PRETEND_VERSION=1.2.3",
+            ),
+            (
+                BuildPlatform::Unix,
+                "export SETUPTOOLS_SCM_PRETEND_VERSION=1.2.3",
+            ),
+        ] {
+            let script = BuildScriptContext {
+                installer: Installer::Pip,
+                build_platform,
+                editable: false,
+                extra_args: vec![],
+                manifest_root: PathBuf::from("my-package"),
+                setuptools_scm_version: Some(String::from("1.2.3")),
+                extras: vec![],
+                clean_python_env: false,
+                cache_dir: None,
+                no_cache: false,
+                pip_check: false,
+            }
+            .render();
+            assert!(
+                script.starts_with(export),
+                "version is not exported in:\n{script}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extras_are_installed() {
+        let script = BuildScriptContext {
+            installer: Installer::Pip,
+            build_platform: BuildPlatform::Unix,
+            editable: false,
+            extra_args: vec![],
+            manifest_root: PathBuf::from("my-package"),
+            setuptools_scm_version: None,
+            extras: vec![String::from("viz"), String::from("ml")],
+            clean_python_env: false,
+            cache_dir: None,
+            no_cache: false,
+            pip_check: false,
+        }
+        .render();
+        assert!(
+            script.contains("\"my-package[viz,ml]\""),
+            "extras are not installed in:\n{script}"
+        );
+    }
+
+    #[test]
+    fn test_python_env_is_cleaned_before_install() {
+        for (build_platform, clear) in [
+            (
+                BuildPlatform::Windows,
+                "set PYTHONPATH=\nset PYTHONSTARTUP=\nset PYTHONNOUSERSITE=1\n",
+            ),
+            (
+                BuildPlatform::Unix,
+                "unset PYTHONPATH PYTHONSTARTUP\nexport PYTHONNOUSERSITE=1\n",
+            ),
+        ] {
+            let script = BuildScriptContext {
+                installer: Installer::Pip,
+                build_platform,
+                editable: false,
+                extra_args: vec![],
+                manifest_root: PathBuf::from("my-package"),
+                setuptools_scm_version: None,
+                extras: vec![],
+                clean_python_env: true,
+                cache_dir: None,
+                no_cache: false,
+                pip_check: false,
+            }
+            .render();
+            assert!(
+                script.starts_with(clear),
+                "python env is not cleaned in:\n{script}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_cache_dir_depends_on_installer() {
+        for (build_platform, installer, cache_dir) in [
+            (
+                BuildPlatform::Windows,
+                Installer::Pip,
+                "--cache-dir \"%USERPROFILE%\\.cache\\pixi-build\\pip\"",
+            ),
+            (
+                BuildPlatform::Unix,
+                Installer::Pip,
+                "--cache-dir \"$HOME/.cache/pixi-build/pip\"",
+            ),
+            (
+                BuildPlatform::Unix,
+                Installer::Uv,
+                "--cache-dir \"$HOME/.cache/pixi-build/uv\"",
+            ),
+        ] {
+            let script = BuildScriptContext {
+                installer,
+                build_platform,
+                editable: false,
+                extra_args: vec![],
+                manifest_root: PathBuf::from("my-package"),
+                setuptools_scm_version: None,
+                extras: vec![],
+                clean_python_env: false,
+                cache_dir: None,
+                no_cache: false,
+                pip_check: false,
+            }
+            .render();
+            assert!(
+                script.contains(cache_dir),
+                "default cache dir is not used in:\n{script}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_pip_check_does_not_fail_the_build() {
+        for (build_platform, installer, check) in [
+            (
+                BuildPlatform::Windows,
+                Installer::Pip,
+                "\"%PYTHON%\" -m pip check\nif errorlevel 1 (\n    echo WARNING:",
+            ),
+            (
+                BuildPlatform::Unix,
+                Installer::Uv,
+                "uv pip check --python \"$PYTHON\" || echo \"WARNING:",
+            ),
+        ] {
+            let script = BuildScriptContext {
+                installer,
+                build_platform,
+                editable: false,
+                extra_args: vec![],
+                manifest_root: PathBuf::from("my-package"),
+                setuptools_scm_version: None,
+                extras: vec![],
+                clean_python_env: false,
+                cache_dir: None,
+                no_cache: false,
+                pip_check: true,
+            }
+            .render();
+            assert!(script.contains(check), "pip check is not run in:\n{script}");
+        }
+    }
+
+    #[test]
+    fn test_pip_check_windows() {
+        let script = BuildScriptContext {
+            installer: Installer::Pip,
+            build_platform: BuildPlatform::Windows,
+            editable: false,
+            extra_args: vec![],
+            manifest_root: PathBuf::from("my-package"),
+            setuptools_scm_version: None,
+            extras: vec![],
+            clean_python_env: true,
+            cache_dir: None,
+            no_cache: true,
+            pip_check: true,
+        }
+        .render();
+
+        // The warning of a failed `pip check` must reset the error level, the
+        // build script would fail otherwise.
+        insta::assert_snapshot!(script);
+    }
+}
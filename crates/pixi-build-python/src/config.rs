@@ -1,4 +1,5 @@
 use indexmap::IndexMap;
+use miette::{Context, IntoDiagnostic};
 use pixi_build_backend::generated_recipe::BackendConfig;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -23,12 +24,42 @@ pub struct PythonBackendConfig {
     /// Extra input globs to include in addition to the default ones
     #[serde(default)]
     pub extra_input_globs: Vec<String>,
+    /// Globs of input files to exclude from the default and extra input
+    /// globs, e.g. `**/__pycache__/**`. Uses the same glob syntax as the
+    /// input globs.
+    #[serde(default)]
+    pub exclude_input_globs: Vec<String>,
     /// List of compilers to use (e.g., ["c", "cxx", "rust"])
     /// If not specified, no compilers are added (since most Python packages are pure Python)
     pub compilers: Option<Vec<String>>,
     /// Ignore the pyproject.toml manifest and rely only on the project model.
     #[serde(default)]
     pub ignore_pyproject_manifest: Option<bool>,
+    /// Extras of `project.optional-dependencies` to install the package with.
+    /// Their dependencies are added to the run requirements.
+    #[serde(default)]
+    pub with_extras: Vec<String>,
+    /// Add the dependencies of `with-extras` to the run constraints instead
+    /// of the run requirements. Defaults to `false`.
+    pub extras_as_run_constraints: Option<bool>,
+    /// Keep `PYTHONPATH`, `PYTHONSTARTUP` and `PYTHONNOUSERSITE` of the
+    /// calling environment. By default the first two are cleared and
+    /// `PYTHONNOUSERSITE=1` is set before the install, so the user
+    /// site-packages are not used either. Defaults to `false`.
+    pub preserve_pythonpath: Option<bool>,
+    /// The directory pip or uv caches wheels in (`--cache-dir`). Defaults to
+    /// `$HOME/.cache/pixi-build/pip` or `$HOME/.cache/pixi-build/uv`.
+    pub wheel_cache_dir: Option<PathBuf>,
+    /// Do not cache wheels at all. Defaults to `false`.
+    pub no_cache: Option<bool>,
+    /// Run `pip check` after the installation to report conflicting
+    /// dependencies. Defaults to `false`.
+    pub run_pip_check: Option<bool>,
+    /// Only build a noarch package if the pyproject.toml declares a
+    /// `Programming Language :: Python :: 3` classifier and does not require
+    /// tools to build native extensions, unless `noarch` is set. Defaults to
+    /// `false`.
+    pub infer_noarch_from_classifiers: Option<bool>,
 }
 
 impl PythonBackendConfig {
@@ -37,6 +68,58 @@ impl PythonBackendConfig {
         self.noarch.unwrap_or(true)
     }
 
+    /// Reads the `[tool.pixi-build-python]` table of the pyproject.toml in
+    /// `manifest_root`. Returns `None` if there is no pyproject.toml or it
+    /// does not contain the table.
+    pub fn from_pyproject(manifest_root: &Path) -> miette::Result<Option<Self>> {
+        let pyproject_path = manifest_root.join("pyproject.toml");
+        if !pyproject_path.is_file() {
+            return Ok(None);
+        }
+
+        let contents = fs_err::read_to_string(&pyproject_path).into_diagnostic()?;
+        let pyproject = toml::from_str::<toml::Table>(&contents).into_diagnostic()?;
+        let Some(table) = pyproject
+            .get("tool")
+            .and_then(|tool| tool.get("pixi-build-python"))
+        else {
+            return Ok(None);
+        };
+
+        table
+            .clone()
+            .try_into()
+            .into_diagnostic()
+            .with_context(|| {
+                format!(
+                    "failed to parse `[tool.pixi-build-python]` in {}",
+                    pyproject_path.display()
+                )
+            })
+            .map(Some)
+    }
+
+    /// Merges the `[tool.pixi-build-python]` table of the pyproject.toml in
+    /// `manifest_root` into this configuration. Values set in this
+    /// configuration take precedence over the ones of the pyproject.toml.
+    pub fn with_pyproject_config(&self, manifest_root: &Path) -> miette::Result<Self> {
+        if self.ignore_pyproject_manifest.is_some_and(|ignore| ignore) {
+            return Ok(self.clone());
+        }
+        let Some(pyproject_config) = Self::from_pyproject(manifest_root)? else {
+            return Ok(self.clone());
+        };
+
+        // `debug_dir` cannot be merged as a target specific value, it is
+        // deprecated anyway so keep the one of this configuration.
+        let mut merged = pyproject_config.merge_with_target_config(&Self {
+            debug_dir: None,
+            ..self.clone()
+        })?;
+        merged.debug_dir = self.debug_dir.clone();
+        Ok(merged)
+    }
+
     /// Creates a new [`PythonBackendConfig`] with default values and
     /// `ignore_pyproject_manifest` set to `true`.
     #[cfg(test)]
@@ -60,6 +143,15 @@ impl BackendConfig for PythonBackendConfig {
     /// - extra_args: Platform-specific completely replaces base
     /// - debug_dir: Not allowed to have target specific value
     /// - extra_input_globs: Platform-specific completely replaces base
+    /// - exclude_input_globs: Platform-specific completely replaces base
+    /// - with_extras: Platform-specific completely replaces base
+    /// - extras_as_run_constraints: Platform-specific takes precedence over base
+    /// - preserve_pythonpath: Platform-specific takes precedence over base
+    /// - wheel_cache_dir: Platform-specific takes precedence over base
+    /// - no_cache: Platform-specific takes precedence over base
+    /// - run_pip_check: Platform-specific takes precedence over base
+    /// - infer_noarch_from_classifiers: Platform-specific takes precedence over
+    ///   base
     fn merge_with_target_config(&self, target_config: &Self) -> miette::Result<Self> {
         if target_config.debug_dir.is_some() {
             miette::bail!("`debug_dir` cannot have a target specific value");
@@ -83,6 +175,11 @@ impl BackendConfig for PythonBackendConfig {
             } else {
                 target_config.extra_input_globs.clone()
             },
+            exclude_input_globs: if target_config.exclude_input_globs.is_empty() {
+                self.exclude_input_globs.clone()
+            } else {
+                target_config.exclude_input_globs.clone()
+            },
             compilers: target_config
                 .compilers
                 .clone()
@@ -90,6 +187,26 @@ impl BackendConfig for PythonBackendConfig {
             ignore_pyproject_manifest: target_config
                 .ignore_pyproject_manifest
                 .or(self.ignore_pyproject_manifest),
+            with_extras: if target_config.with_extras.is_empty() {
+                self.with_extras.clone()
+            } else {
+                target_config.with_extras.clone()
+            },
+            extras_as_run_constraints: target_config
+                .extras_as_run_constraints
+                .or(self.extras_as_run_constraints),
+            preserve_pythonpath: target_config
+                .preserve_pythonpath
+                .or(self.preserve_pythonpath),
+            wheel_cache_dir: target_config
+                .wheel_cache_dir
+                .clone()
+                .or_else(|| self.wheel_cache_dir.clone()),
+            no_cache: target_config.no_cache.or(self.no_cache),
+            run_pip_check: target_config.run_pip_check.or(self.run_pip_check),
+            infer_noarch_from_classifiers: target_config
+                .infer_noarch_from_classifiers
+                .or(self.infer_noarch_from_classifiers),
         })
     }
 }
@@ -119,8 +236,16 @@ mod tests {
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_args: vec!["-Cbuilddir=mybuilddir".into()],
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             compilers: Some(vec!["c".to_string()]),
             ignore_pyproject_manifest: Some(true),
+            with_extras: vec![],
+            extras_as_run_constraints: None,
+            preserve_pythonpath: None,
+            wheel_cache_dir: None,
+            no_cache: None,
+            run_pip_check: None,
+            infer_noarch_from_classifiers: None,
         };
 
         let mut target_env = indexmap::IndexMap::new();
@@ -133,8 +258,16 @@ mod tests {
             debug_dir: None,
             extra_args: vec![],
             extra_input_globs: vec!["*.target".to_string()],
+            exclude_input_globs: vec![],
             compilers: Some(vec!["cxx".to_string(), "rust".to_string()]),
             ignore_pyproject_manifest: Some(false),
+            with_extras: vec![],
+            extras_as_run_constraints: None,
+            preserve_pythonpath: None,
+            wheel_cache_dir: None,
+            no_cache: None,
+            run_pip_check: None,
+            infer_noarch_from_classifiers: None,
         };
 
         let merged = base_config
@@ -181,8 +314,16 @@ mod tests {
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_args: vec!["-Cbuilddir=mybuilddir".into()],
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             compilers: None,
             ignore_pyproject_manifest: Some(true),
+            with_extras: vec![],
+            extras_as_run_constraints: None,
+            preserve_pythonpath: None,
+            wheel_cache_dir: None,
+            no_cache: None,
+            run_pip_check: None,
+            infer_noarch_from_classifiers: None,
         };
 
         let empty_target_config = PythonBackendConfig::default();
@@ -273,4 +414,57 @@ mod tests {
         let error_msg = result.unwrap_err().to_string();
         assert!(error_msg.contains("`debug_dir` cannot have a target specific value"));
     }
+
+    #[test]
+    fn test_pyproject_config_is_merged() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"[project]
+name = "foobar"
+
+[tool.pixi-build-python]
+noarch = true
+extra-args = ["--no-deps"]
+env = { FROM_PYPROJECT = "1", SHARED = "pyproject" }
+"#,
+        )
+        .unwrap();
+
+        let mut env = indexmap::IndexMap::new();
+        env.insert("SHARED".to_string(), "json".to_string());
+        let config = PythonBackendConfig {
+            extra_args: vec!["-v".to_string()],
+            env,
+            ..Default::default()
+        };
+
+        let merged = config.with_pyproject_config(temp_dir.path()).unwrap();
+
+        // Values only set in the pyproject.toml are used
+        assert_eq!(merged.noarch, Some(true));
+        assert_eq!(merged.env.get("FROM_PYPROJECT"), Some(&"1".to_string()));
+
+        // Values of the backend configuration take precedence
+        assert_eq!(merged.extra_args, vec!["-v".to_string()]);
+        assert_eq!(merged.env.get("SHARED"), Some(&"json".to_string()));
+
+        // The pyproject.toml is not read if it is ignored
+        let ignored = PythonBackendConfig::default_with_ignore_pyproject_manifest()
+            .with_pyproject_config(temp_dir.path())
+            .unwrap();
+        assert_eq!(ignored.noarch, None);
+    }
+
+    #[test]
+    fn test_pyproject_config_unknown_field() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[tool.pixi-build-python]\nnot-a-field = true\n",
+        )
+        .unwrap();
+
+        assert!(PythonBackendConfig::from_pyproject(temp_dir.path()).is_err());
+    }
 }
@@ -0,0 +1,140 @@
+use std::path::Path;
+
+use pixi_build_backend::lint::LintDiagnostic;
+use pixi_build_types::ProjectModelV1;
+use pyproject_toml::PyProjectToml;
+
+use crate::{config::PythonBackendConfig, pypi_mapping::PyPiToCondaMapper};
+
+/// Checks the pyproject.toml in `manifest_root` for metadata that contradicts
+/// the project model or the backend configuration. Nothing is checked if the
+/// pyproject.toml is missing or ignored.
+pub fn lint_pyproject(
+    model: &ProjectModelV1,
+    config: &PythonBackendConfig,
+    manifest_root: &Path,
+) -> Vec<LintDiagnostic> {
+    let pyproject_path = manifest_root.join("pyproject.toml");
+    if config
+        .ignore_pyproject_manifest
+        .is_some_and(|ignore| ignore)
+        || !pyproject_path.is_file()
+    {
+        return Vec::new();
+    }
+
+    let pyproject = match fs_err::read_to_string(&pyproject_path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| {
+            toml::from_str::<PyProjectToml>(&contents).map_err(|err| err.to_string())
+        }) {
+        Ok(pyproject) => pyproject,
+        Err(err) => {
+            return vec![LintDiagnostic::error(
+                format!("failed to parse the pyproject.toml: {err}"),
+                pyproject_path,
+            )];
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+    let project = pyproject.project.as_ref();
+
+    let declared_name = model.name.as_ref().zip(project).filter(|(name, project)| {
+        PyPiToCondaMapper.conda_name(&project.name) != name.to_lowercase()
+    });
+    if let Some((name, project)) = declared_name {
+        diagnostics.push(LintDiagnostic::warning(
+            format!(
+                "the package is called `{name}`, but the pyproject.toml declares `{}`",
+                project.name
+            ),
+            pyproject_path.clone(),
+        ));
+    }
+
+    let declared_version = model
+        .version
+        .as_ref()
+        .zip(project.and_then(|project| project.version.as_ref()))
+        .filter(|(version, project_version)| version.to_string() != project_version.to_string());
+    if let Some((version, project_version)) = declared_version {
+        diagnostics.push(LintDiagnostic::warning(
+            format!(
+                "the package version is `{version}`, but the pyproject.toml declares `{project_version}`"
+            ),
+            pyproject_path.clone(),
+        ));
+    }
+
+    let optional_dependencies = project.and_then(|project| project.optional_dependencies.as_ref());
+    for extra in &config.with_extras {
+        if !optional_dependencies.is_some_and(|deps| deps.contains_key(extra)) {
+            diagnostics.push(LintDiagnostic::error(
+                format!(
+                    "the extra `{extra}` of `with-extras` is not declared in `project.optional-dependencies`"
+                ),
+                pyproject_path.clone(),
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use pixi_build_backend::lint::LintSeverity;
+
+    use super::*;
+
+    fn lint(pyproject: &str, config: &PythonBackendConfig) -> Vec<LintDiagnostic> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("pyproject.toml"), pyproject).unwrap();
+        let model = serde_json::from_value::<ProjectModelV1>(serde_json::json!({
+            "name": "foobar",
+            "version": "0.1.0",
+        }))
+        .unwrap();
+        lint_pyproject(&model, config, temp_dir.path())
+    }
+
+    #[test]
+    fn test_consistent_pyproject() {
+        let diagnostics = lint(
+            "[project]\nname = \"FooBar\"\nversion = \"0.1.0\"\n",
+            &PythonBackendConfig::default(),
+        );
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_inconsistent_pyproject() {
+        let diagnostics = lint(
+            "[project]\nname = \"other\"\nversion = \"0.2.0\"\n",
+            &PythonBackendConfig {
+                with_extras: vec!["viz".to_string()],
+                ..Default::default()
+            },
+        );
+        let severities = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.severity)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            severities,
+            vec![
+                LintSeverity::Warning,
+                LintSeverity::Warning,
+                LintSeverity::Error
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_pyproject() {
+        let diagnostics = lint("[project\n", &PythonBackendConfig::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, LintSeverity::Error);
+    }
+}
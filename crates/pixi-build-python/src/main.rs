@@ -1,6 +1,10 @@
 mod build_script;
 mod config;
+mod lint;
+mod manifest_in;
 mod metadata;
+mod pypi_mapping;
+mod setup_cfg;
 
 use build_script::{BuildPlatform, BuildScriptContext, Installer};
 use config::PythonBackendConfig;
@@ -10,6 +14,7 @@ use pixi_build_backend::{
     Variable,
     generated_recipe::{GenerateRecipe, GeneratedRecipe, PythonParams},
     intermediate_backend::IntermediateBackendInstantiator,
+    lint::LintDiagnostic,
     traits::ProjectModel,
 };
 use pixi_build_types::ProjectModelV1;
@@ -26,30 +31,141 @@ use std::{
 };
 
 use crate::metadata::PyprojectMetadataProvider;
+use crate::pypi_mapping::PyPiToCondaMapper;
 
 #[derive(Default, Clone)]
 pub struct PythonGenerator {}
 
 impl PythonGenerator {
-    /// Read the entry points from the pyproject.toml and return them as a list.
-    ///
-    /// If the manifest is not a pyproject.toml file no entry-points are added.
-    pub(crate) fn entry_points(pyproject_manifest: Option<PyProjectToml>) -> Vec<EntryPoint> {
+    /// Read the entry points from the pyproject.toml and the `setup.cfg` in
+    /// `manifest_root` and return them as a list. If both declare a script
+    /// with the same name, the one of the pyproject.toml is used.
+    pub(crate) fn entry_points(
+        pyproject_manifest: Option<PyProjectToml>,
+        manifest_root: &Path,
+    ) -> Vec<EntryPoint> {
         let scripts = pyproject_manifest
             .as_ref()
             .and_then(|p| p.project.as_ref())
             .and_then(|p| p.scripts.as_ref());
 
-        scripts
+        let setup_cfg_scripts = fs_err::read_to_string(manifest_root.join("setup.cfg"))
+            .map(|setup_cfg| setup_cfg::console_scripts(&setup_cfg))
+            .unwrap_or_default();
+
+        let mut entry_points: Vec<EntryPoint> = scripts
             .into_iter()
             .flatten()
             .flat_map(|(name, entry_point)| {
                 EntryPoint::from_str(&format!("{name} = {entry_point}"))
             })
-            .collect()
+            .collect();
+        for entry_point in setup_cfg_scripts
+            .iter()
+            .flat_map(|script| EntryPoint::from_str(script))
+        {
+            if !entry_points
+                .iter()
+                .any(|existing| existing.command == entry_point.command)
+            {
+                entry_points.push(entry_point);
+            }
+        }
+        entry_points
     }
 }
 
+/// Build requirements that indicate a package contains compiled extensions
+/// and therefore cannot be built as a `noarch: python` package.
+const NATIVE_BUILD_REQUIREMENTS: &[&str] = &[
+    "cython",
+    "cffi",
+    "pybind11",
+    "maturin",
+    "setuptools-rust",
+    "ninja",
+];
+
+/// Returns true if any of the `build-system.requires` entries of the
+/// pyproject.toml points at a tool used to build native extensions.
+fn requires_native_build_tools(pyproject: &PyProjectToml) -> bool {
+    pyproject.build_system.as_ref().is_some_and(|build_system| {
+        build_system.requires.iter().any(|requirement| {
+            let name = requirement.name.to_string().to_lowercase();
+            NATIVE_BUILD_REQUIREMENTS.contains(&name.as_str())
+        })
+    })
+}
+
+/// Maps build requirements to the compilers they need.
+const BUILD_REQUIREMENT_COMPILERS: &[(&str, &str)] =
+    &[("maturin", "rust"), ("setuptools-rust", "rust")];
+
+/// Detects the compilers that are required to build a package from the
+/// `build-system.requires` entries of its pyproject.toml.
+fn detect_compilers_from_build_requirements(pyproject: &PyProjectToml) -> Vec<String> {
+    let mut compilers = Vec::new();
+    let requirements = pyproject
+        .build_system
+        .iter()
+        .flat_map(|build_system| build_system.requires.iter());
+    for requirement in requirements {
+        let name = requirement.name.to_string().to_lowercase();
+        for (_, compiler) in BUILD_REQUIREMENT_COMPILERS
+            .iter()
+            .filter(|(requirement, _)| *requirement == name)
+        {
+            if !compilers.iter().any(|c| c == compiler) {
+                compilers.push(compiler.to_string());
+            }
+        }
+    }
+    compilers
+}
+
+/// Returns true if the version of the project is determined by
+/// `setuptools-scm`, i.e. the version is dynamic and `setuptools-scm` is one of
+/// the `build-system.requires` entries.
+fn uses_setuptools_scm(pyproject: &PyProjectToml) -> bool {
+    let has_dynamic_version = pyproject
+        .project
+        .as_ref()
+        .and_then(|project| project.dynamic.as_ref())
+        .is_some_and(|dynamic| dynamic.iter().any(|field| field == "version"));
+
+    has_dynamic_version
+        && pyproject.build_system.as_ref().is_some_and(|build_system| {
+            build_system.requires.iter().any(|requirement| {
+                requirement
+                    .name
+                    .to_string()
+                    .to_lowercase()
+                    .replace('_', "-")
+                    == "setuptools-scm"
+            })
+        })
+}
+
+/// Infers whether a package can be built as a `noarch: python` package from
+/// its pyproject.toml.
+///
+/// This is the case when the project declares a `Programming Language ::
+/// Python :: 3` classifier and none of the build requirements are used to
+/// build native extensions.
+fn infer_noarch_from_pyproject(pyproject: &PyProjectToml) -> bool {
+    let is_python3 = pyproject
+        .project
+        .as_ref()
+        .and_then(|project| project.classifiers.as_ref())
+        .is_some_and(|classifiers| {
+            classifiers
+                .iter()
+                .any(|classifier| classifier.starts_with("Programming Language :: Python :: 3"))
+        });
+
+    is_python3 && !requires_native_build_tools(pyproject)
+}
+
 impl GenerateRecipe for PythonGenerator {
     type Config = PythonBackendConfig;
 
@@ -81,6 +197,10 @@ impl GenerateRecipe for PythonGenerator {
             manifest_path.clone()
         };
 
+        // Settings of the `[tool.pixi-build-python]` table of the pyproject.toml
+        // apply unless the backend configuration sets them.
+        let config = &config.with_pyproject_config(&manifest_root)?;
+
         let mut pyproject_metadata_provider = PyprojectMetadataProvider::new(
             &manifest_root,
             config
@@ -92,6 +212,47 @@ impl GenerateRecipe for PythonGenerator {
             GeneratedRecipe::from_model(model.clone(), &mut pyproject_metadata_provider)
                 .into_diagnostic()?;
 
+        // read pyproject.toml content if it exists
+        let pyproject_manifest_path = manifest_root.join("pyproject.toml");
+        let pyproject_manifest: Option<PyProjectToml> = if pyproject_manifest_path.exists() {
+            let contents = std::fs::read_to_string(&pyproject_manifest_path).into_diagnostic()?;
+            generated_recipe.build_input_globs = BTreeSet::from(["pyproject.toml".to_string()]);
+            Some(toml::from_str(&contents).into_diagnostic()?)
+        } else {
+            None
+        };
+
+        // The `setup.cfg` can declare additional entry points.
+        let setup_cfg_path = manifest_root.join("setup.cfg");
+        if setup_cfg_path.is_file() {
+            generated_recipe
+                .build_input_globs
+                .insert("setup.cfg".to_string());
+        }
+
+        // The files that the `MANIFEST.in` ships with the package, e.g. data
+        // files, are inputs of the build as well. The globs only decide when
+        // to rebuild, so an unreadable `MANIFEST.in` does not fail the build.
+        let manifest_in_globs =
+            manifest_in::parse_manifest_in(&manifest_root).unwrap_or_else(|err| {
+                tracing::warn!("failed to read the MANIFEST.in, its files are not tracked: {err}");
+                Vec::new()
+            });
+        if !manifest_in_globs.is_empty() {
+            generated_recipe
+                .build_input_globs
+                .insert("MANIFEST.in".to_string());
+            generated_recipe.build_input_globs.extend(manifest_in_globs);
+        }
+
+        // The pyproject.toml is only used to infer build settings if it is not
+        // ignored.
+        let inferred_pyproject = pyproject_manifest.as_ref().filter(|_| {
+            !config
+                .ignore_pyproject_manifest
+                .is_some_and(|ignore| ignore)
+        });
+
         let requirements = &mut generated_recipe.recipe.requirements;
 
         // Get the platform-specific dependencies from the project model.
@@ -136,9 +297,49 @@ impl GenerateRecipe for PythonGenerator {
             requirements.run.push(get_python_requirement()?);
         }
 
-        // Get the list of compilers from config, defaulting to no compilers for pure
+        // Constrain the python version of downstream environments to the
+        // `requires-python` bounds.
+        if let Ok(Some(requires_python)) = pyproject_metadata_provider.requires_python() {
+            requirements.run_constraints.push(
+                format!("python {requires_python}")
+                    .parse()
+                    .into_diagnostic()?,
+            );
+        }
+
+        // Add the dependencies of the requested extras. Extras are opt-in, so
+        // they can be demoted to run constraints.
+        if !config.with_extras.is_empty() {
+            let Some(pyproject) = inferred_pyproject else {
+                miette::bail!(
+                    "`with-extras` requires a pyproject.toml that declares `project.optional-dependencies`"
+                );
+            };
+            let extras_requirements = if config.extras_as_run_constraints.is_some_and(|c| c) {
+                &mut requirements.run_constraints
+            } else {
+                &mut requirements.run
+            };
+            for spec in PyPiToCondaMapper.extra_dependencies(pyproject, &config.with_extras)? {
+                let name = spec.split(' ').next().unwrap_or_default();
+                if model_dependencies
+                    .run
+                    .contains_key(&pixi_build_types::SourcePackageName::from(name))
+                {
+                    continue;
+                }
+                extras_requirements.push(spec.parse().into_diagnostic()?);
+            }
+        }
+
+        // Get the list of compilers from config, or detect them from the build
+        // requirements in the pyproject.toml, defaulting to no compilers for pure
         // Python packages and add them to the build requirements.
-        let compilers = config.compilers.clone().unwrap_or_default();
+        let compilers = config.compilers.clone().unwrap_or_else(|| {
+            inferred_pyproject
+                .map(detect_compilers_from_build_requirements)
+                .unwrap_or_default()
+        });
         pixi_build_backend::compilers::add_compilers_to_requirements(
             &compilers,
             &mut requirements.build,
@@ -153,11 +354,6 @@ impl GenerateRecipe for PythonGenerator {
 
         let build_platform = Platform::current();
 
-        // TODO: remove this env var override as soon as we have profiles
-        let editable = std::env::var("BUILD_EDITABLE_PYTHON")
-            .map(|val| val == "true")
-            .unwrap_or(params.editable);
-
         let build_script = BuildScriptContext {
             installer,
             build_platform: if build_platform.is_windows() {
@@ -165,9 +361,19 @@ impl GenerateRecipe for PythonGenerator {
             } else {
                 BuildPlatform::Unix
             },
-            editable,
+            editable: params.editable,
             extra_args: config.extra_args.clone(),
             manifest_root: manifest_root.clone(),
+            // There is no git metadata in the build environment, so
+            // `setuptools-scm` has to be told the version.
+            setuptools_scm_version: inferred_pyproject
+                .is_some_and(uses_setuptools_scm)
+                .then(|| generated_recipe.recipe.package.version.to_string()),
+            extras: config.with_extras.clone(),
+            clean_python_env: !config.preserve_pythonpath.is_some_and(|preserve| preserve),
+            cache_dir: config.wheel_cache_dir.clone(),
+            no_cache: config.no_cache.is_some_and(|no_cache| no_cache),
+            pip_check: config.run_pip_check.unwrap_or(false),
         }
         .render();
 
@@ -183,26 +389,23 @@ impl GenerateRecipe for PythonGenerator {
         } else if has_compilers {
             // No specific user request, but we have compilers, not a noarch package.
             None
+        } else if let Some(pyproject) = inferred_pyproject.filter(|_| {
+            config
+                .infer_noarch_from_classifiers
+                .is_some_and(|infer| infer)
+        }) {
+            // The user asked to infer noarch from the classifiers and the
+            // build requirements.
+            infer_noarch_from_pyproject(pyproject).then_some(NoArchKind::Python)
         } else {
             // Otherwise, default to a noarch package.
             // This is the default behavior for pure Python packages.
             Some(NoArchKind::Python)
         };
 
-        // read pyproject.toml content if it exists
-        let pyproject_manifest_path = manifest_root.join("pyproject.toml");
-        let pyproject_manifest = if pyproject_manifest_path.exists() {
-            let contents = std::fs::read_to_string(&pyproject_manifest_path).into_diagnostic()?;
-            generated_recipe.build_input_globs =
-                BTreeSet::from([pyproject_manifest_path.to_string_lossy().to_string()]);
-            Some(toml::from_str(&contents).into_diagnostic()?)
-        } else {
-            None
-        };
-
         // Construct python specific settings
         let python = Python {
-            entry_points: PythonGenerator::entry_points(pyproject_manifest),
+            entry_points: PythonGenerator::entry_points(pyproject_manifest, &manifest_root),
         };
 
         generated_recipe.recipe.build.python = python;
@@ -227,6 +430,15 @@ impl GenerateRecipe for PythonGenerator {
         Ok(generated_recipe)
     }
 
+    fn lint(
+        &self,
+        model: &ProjectModelV1,
+        config: &Self::Config,
+        manifest_root: &Path,
+    ) -> miette::Result<Vec<LintDiagnostic>> {
+        Ok(lint::lint_pyproject(model, config, manifest_root))
+    }
+
     /// Determines the build input globs for given python package
     /// even this will be probably backend specific, e.g setuptools
     /// has a different way of determining the input globs than hatch etc.
@@ -235,9 +447,12 @@ impl GenerateRecipe for PythonGenerator {
     fn extract_input_globs_from_build(
         &self,
         config: &Self::Config,
-        _workdir: impl AsRef<Path>,
+        manifest_root: impl AsRef<Path>,
         editable: bool,
     ) -> miette::Result<BTreeSet<String>> {
+        // Settings of the `[tool.pixi-build-python]` table of the pyproject.toml
+        // can add input globs as well.
+        let config = &config.with_pyproject_config(manifest_root.as_ref())?;
         let base_globs = Vec::from([
             // Project configuration
             "setup.py",
@@ -273,6 +488,12 @@ impl GenerateRecipe for PythonGenerator {
             .chain(compiler_based_globs.iter())
             .map(|s| s.to_string())
             .chain(config.extra_input_globs.clone())
+            .chain(
+                config
+                    .exclude_input_globs
+                    .iter()
+                    .map(|glob| format!("!{glob}")),
+            )
             .collect())
     }
 
@@ -565,6 +786,72 @@ version = "0.1.0"
         });
     }
 
+    #[test]
+    fn test_user_host_constraints_are_kept() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+            "targets": {
+                "defaultTarget": {
+                    "hostDependencies": {
+                        "python": {
+                            "binary": {
+                                "version": ">=3.11,<3.12"
+                            }
+                        },
+                        "uv": {
+                            "binary": {
+                                "version": ">=0.4"
+                            }
+                        }
+                    }
+                },
+            }
+        });
+
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        std::fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[project]\nname = \"foobar\"\nrequires-python = \">=3.8\"\n",
+        )
+        .expect("Failed to write pyproject.toml");
+
+        let generated_recipe = PythonGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &PythonBackendConfig::default(),
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        // The user specified constraints are neither replaced nor duplicated
+        // by the automatically added requirements.
+        let host = generated_recipe
+            .recipe
+            .requirements
+            .host
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        let python = host
+            .iter()
+            .filter(|spec| spec.starts_with("python"))
+            .collect::<Vec<_>>();
+        assert_eq!(python.len(), 1, "{host:?}");
+        assert!(python[0].contains(">=3.11,<3.12"), "{host:?}");
+        let uv = host
+            .iter()
+            .filter(|spec| spec.starts_with("uv"))
+            .collect::<Vec<_>>();
+        assert_eq!(uv.len(), 1, "{host:?}");
+        assert!(uv[0].contains(">=0.4"), "{host:?}");
+        assert!(!host.iter().any(|spec| spec.starts_with("pip")), "{host:?}");
+    }
+
     #[test]
     fn test_env_vars_are_set() {
         let project_model = project_fixture!({
@@ -815,6 +1102,236 @@ version = "0.1.0"
         );
     }
 
+    // Helper function to generate a recipe for a project with the given
+    // pyproject.toml contents
+    fn generate_recipe_with_pyproject(pyproject: &str) -> GeneratedRecipe {
+        generate_recipe_with_pyproject_and_config(pyproject, &PythonBackendConfig::default())
+    }
+
+    fn generate_recipe_with_pyproject_and_config(
+        pyproject: &str,
+        config: &PythonBackendConfig,
+    ) -> GeneratedRecipe {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        std::fs::write(temp_dir.path().join("pyproject.toml"), pyproject)
+            .expect("Failed to write pyproject.toml");
+
+        PythonGenerator::default()
+            .generate_recipe(
+                &minimal_project(),
+                config,
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe")
+    }
+
+    #[test]
+    fn test_requires_python_pins_python() {
+        for (requires_python, expected) in
+            [(">=3.9", "python >=3.9"), ("==3.11.*", "python 3.11.*")]
+        {
+            let generated_recipe = generate_recipe_with_pyproject(&format!(
+                r#"[project]
+name = "foobar"
+version = "0.1.0"
+requires-python = "{requires_python}"
+"#
+            ));
+            let requirements = &generated_recipe.recipe.requirements;
+
+            for (section, items) in [
+                ("host", &requirements.host),
+                ("run", &requirements.run),
+                ("run_constraints", &requirements.run_constraints),
+            ] {
+                let items = items
+                    .iter()
+                    .map(|item| item.to_string())
+                    .collect::<Vec<_>>();
+                assert!(
+                    items.iter().any(|item| item.starts_with(expected)),
+                    "{section} should contain '{expected}', found: {items:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_noarch_inferred_from_classifiers() {
+        let generated_recipe = generate_recipe_with_pyproject_and_config(
+            r#"[build-system]
+requires = ["hatchling"]
+
+[project]
+name = "foobar"
+version = "0.1.0"
+classifiers = [
+    "Programming Language :: Python :: 3",
+    "Programming Language :: Python :: 3.12",
+]
+"#,
+            &PythonBackendConfig {
+                infer_noarch_from_classifiers: Some(true),
+                ..Default::default()
+            },
+        );
+
+        insta::assert_yaml_snapshot!(generated_recipe.recipe.build, {
+            ".script" => "[ ... script ... ]",
+        });
+    }
+
+    #[test]
+    fn test_noarch_without_python_version_classifier() {
+        let pyproject = r#"[build-system]
+requires = ["hatchling"]
+
+[project]
+name = "foobar"
+version = "0.1.0"
+classifiers = [
+    "License :: OSI Approved :: MIT License",
+    "Topic :: Utilities",
+]
+"#;
+
+        // Classifiers alone do not make a package platform specific.
+        let generated_recipe = generate_recipe_with_pyproject(pyproject);
+        assert!(matches!(
+            generated_recipe.recipe.build.noarch,
+            Some(NoArchKind::Python)
+        ));
+
+        // Unless the classifiers are used to infer noarch.
+        let generated_recipe = generate_recipe_with_pyproject_and_config(
+            pyproject,
+            &PythonBackendConfig {
+                infer_noarch_from_classifiers: Some(true),
+                ..Default::default()
+            },
+        );
+        assert!(generated_recipe.recipe.build.noarch.is_none());
+    }
+
+    #[test]
+    fn test_noarch_not_inferred_with_cython() {
+        let pyproject = r#"[build-system]
+requires = ["setuptools", "Cython>=3"]
+
+[project]
+name = "foobar"
+version = "0.1.0"
+classifiers = ["Programming Language :: Python :: 3"]
+"#;
+        let generated_recipe = generate_recipe_with_pyproject_and_config(
+            pyproject,
+            &PythonBackendConfig {
+                infer_noarch_from_classifiers: Some(true),
+                ..Default::default()
+            },
+        );
+
+        insta::assert_yaml_snapshot!(generated_recipe.recipe.build, {
+            ".script" => "[ ... script ... ]",
+        });
+
+        // Without inferring, the build requirements do not change the default.
+        let generated_recipe = generate_recipe_with_pyproject(pyproject);
+        assert!(matches!(
+            generated_recipe.recipe.build.noarch,
+            Some(NoArchKind::Python)
+        ));
+    }
+
+    #[test]
+    fn test_maturin_adds_rust_compiler() {
+        let generated_recipe = generate_recipe_with_pyproject(
+            r#"[build-system]
+requires = ["maturin>=1.0,<2.0"]
+build-backend = "maturin"
+
+[project]
+name = "foobar"
+version = "0.1.0"
+"#,
+        );
+
+        let build_requirements: Vec<String> = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect();
+        assert!(
+            build_requirements.contains(&"${{ compiler('rust') }}".to_string()),
+            "the rust compiler should be in the build requirements: {build_requirements:?}"
+        );
+        assert!(generated_recipe.recipe.build.noarch.is_none());
+    }
+
+    #[test]
+    fn test_detect_compilers_from_build_requirements() {
+        let pyproject: PyProjectToml = toml::from_str(
+            r#"[build-system]
+requires = ["setuptools", "setuptools-rust", "maturin"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            detect_compilers_from_build_requirements(&pyproject),
+            vec!["rust".to_string()]
+        );
+
+        let pyproject: PyProjectToml = toml::from_str(
+            r#"[build-system]
+requires = ["hatchling"]
+"#,
+        )
+        .unwrap();
+        assert!(detect_compilers_from_build_requirements(&pyproject).is_empty());
+    }
+
+    #[test]
+    fn test_input_globs_from_pyproject_config() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"[tool.pixi-build-python]
+extra-input-globs = ["data/**/*.json"]
+exclude-input-globs = ["**/__pycache__/**"]
+"#,
+        )
+        .unwrap();
+
+        let generator = PythonGenerator::default();
+        let result = generator
+            .extract_input_globs_from_build(&PythonBackendConfig::default(), temp_dir.path(), false)
+            .unwrap();
+
+        assert!(result.contains("data/**/*.json"));
+        assert!(result.contains("!**/__pycache__/**"));
+    }
+
+    #[test]
+    fn test_input_globs_excludes_exclude_globs() {
+        let config = PythonBackendConfig {
+            exclude_input_globs: vec!["**/__pycache__/**".to_string()],
+            ..Default::default()
+        };
+        let generator = PythonGenerator::default();
+        let result = generator
+            .extract_input_globs_from_build(&config, PathBuf::new(), false)
+            .unwrap();
+
+        assert!(result.contains("!**/__pycache__/**"));
+        assert!(result.contains("**/*.py"));
+    }
+
     #[test]
     fn test_c_compilers_create_extra_input_globs() {
         let config = PythonBackendConfig {
@@ -850,4 +1367,318 @@ version = "0.1.0"
         let result = generator.extract_input_globs_from_build(&config, PathBuf::new(), false);
         insta::assert_debug_snapshot!(result);
     }
+
+    #[test]
+    fn test_setuptools_scm_version_is_set() {
+        let generated_recipe = generate_recipe_with_pyproject(
+            r#"[build-system]
+requires = ["setuptools>=64", "setuptools_scm>=8"]
+build-backend = "setuptools.build_meta"
+
+[project]
+name = "foobar"
+dynamic = ["version"]
+
+[tool.setuptools_scm]
+"#,
+        );
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("SETUPTOOLS_SCM_PRETEND_VERSION=0.1.0")
+        );
+
+        // Without a dynamic version setuptools-scm is not used for the version.
+        let generated_recipe = generate_recipe_with_pyproject(
+            r#"[build-system]
+requires = ["setuptools>=64", "setuptools_scm>=8"]
+
+[project]
+name = "foobar"
+version = "0.1.0"
+"#,
+        );
+        assert!(
+            !generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("SETUPTOOLS_SCM_PRETEND_VERSION")
+        );
+    }
+
+    #[test]
+    fn test_pyproject_tool_table_sets_noarch() {
+        // maturin requires a rust compiler, which would make the package
+        // platform specific if noarch was not set.
+        let pyproject = r#"[build-system]
+requires = ["maturin>=1.0"]
+build-backend = "maturin"
+
+[project]
+name = "foobar"
+version = "0.1.0"
+
+[tool.pixi-build-python]
+noarch = true
+"#;
+        let generated_recipe = generate_recipe_with_pyproject(pyproject);
+        assert!(matches!(
+            generated_recipe.recipe.build.noarch,
+            Some(NoArchKind::Python)
+        ));
+
+        // The backend configuration takes precedence over the pyproject.toml.
+        let generated_recipe = generate_recipe_with_pyproject_and_config(
+            pyproject,
+            &PythonBackendConfig {
+                noarch: Some(false),
+                ..Default::default()
+            },
+        );
+        assert!(generated_recipe.recipe.build.noarch.is_none());
+    }
+
+    #[test]
+    fn test_with_extras() {
+        let pyproject = r#"[project]
+name = "foobar"
+version = "0.1.0"
+
+[project.optional-dependencies]
+viz = ["matplotlib>=3.5", "plotly"]
+"#;
+        let items = |items: &Vec<recipe::Item<PackageDependency>>| {
+            items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let generated_recipe = generate_recipe_with_pyproject_and_config(
+            pyproject,
+            &PythonBackendConfig {
+                with_extras: vec!["viz".to_string()],
+                ..Default::default()
+            },
+        );
+        let requirements = &generated_recipe.recipe.requirements;
+        let run = items(&requirements.run);
+        assert!(run.contains(&"matplotlib >=3.5".to_string()), "{run:?}");
+        assert!(run.contains(&"plotly".to_string()), "{run:?}");
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("[viz]\"")
+        );
+
+        let generated_recipe = generate_recipe_with_pyproject_and_config(
+            pyproject,
+            &PythonBackendConfig {
+                with_extras: vec!["viz".to_string()],
+                extras_as_run_constraints: Some(true),
+                ..Default::default()
+            },
+        );
+        let requirements = &generated_recipe.recipe.requirements;
+        assert!(!items(&requirements.run).contains(&"plotly".to_string()));
+        assert!(items(&requirements.run_constraints).contains(&"plotly".to_string()));
+    }
+
+    #[test]
+    fn test_src_layout_is_installed_from_project_root() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        std::fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            r#"[project]
+name = "foobar"
+version = "0.1.0"
+
+[tool.setuptools.packages.find]
+where = ["src"]
+"#,
+        )
+        .expect("Failed to write pyproject.toml");
+        std::fs::create_dir_all(temp_dir.path().join("src/foobar"))
+            .expect("Failed to create package dir");
+        std::fs::write(temp_dir.path().join("src/foobar/__init__.py"), "")
+            .expect("Failed to write __init__.py");
+
+        let generated_recipe = PythonGenerator::default()
+            .generate_recipe(
+                &minimal_project(),
+                &PythonBackendConfig::default(),
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        // The installer needs the directory of the pyproject.toml, the build
+        // backend finds the package in `src/` by itself.
+        let script = &generated_recipe.recipe.build.script.content;
+        assert!(script.contains(&format!("\"{}\"", temp_dir.path().display())));
+        assert!(!script.contains(&temp_dir.path().join("src").display().to_string()));
+    }
+
+    #[test]
+    fn test_wheel_cache_dir_is_passed_to_installer() {
+        let generate = |config: &PythonBackendConfig| {
+            PythonGenerator::default()
+                .generate_recipe(
+                    &minimal_project(),
+                    config,
+                    PathBuf::from("."),
+                    Platform::Linux64,
+                    None,
+                    &HashSet::new(),
+                    vec![],
+                )
+                .expect("Failed to generate recipe")
+                .recipe
+                .build
+                .script
+                .content
+        };
+
+        let script = generate(&PythonBackendConfig {
+            wheel_cache_dir: Some(PathBuf::from("/opt/wheels")),
+            ..PythonBackendConfig::default_with_ignore_pyproject_manifest()
+        });
+        assert!(script.contains("--cache-dir \"/opt/wheels\""), "{script}");
+
+        let script = generate(&PythonBackendConfig {
+            wheel_cache_dir: Some(PathBuf::from("/opt/wheels")),
+            no_cache: Some(true),
+            ..PythonBackendConfig::default_with_ignore_pyproject_manifest()
+        });
+        assert!(!script.contains("--cache-dir"), "{script}");
+    }
+
+    #[test]
+    fn test_entry_points_from_setup_cfg() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        std::fs::write(
+            temp_dir.path().join("setup.cfg"),
+            "[metadata]\nname = foobar\n\n[options.entry_points]\nconsole_scripts =\n    foobar = foobar.cli:main\n",
+        )
+        .expect("Failed to write setup.cfg");
+
+        let generated_recipe = PythonGenerator::default()
+            .generate_recipe(
+                &minimal_project(),
+                &PythonBackendConfig::default(),
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let entry_points = &generated_recipe.recipe.build.python.entry_points;
+        assert_eq!(entry_points.len(), 1);
+        assert_eq!(entry_points[0].to_string(), "foobar = foobar.cli:main");
+    }
+
+    #[test]
+    fn test_manifest_in_extends_input_globs() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        std::fs::write(
+            temp_dir.path().join("pyproject.toml"),
+            "[build-system]\nrequires = [\"setuptools\"]\n",
+        )
+        .expect("Failed to write pyproject.toml");
+        std::fs::write(
+            temp_dir.path().join("MANIFEST.in"),
+            "include LICENSE\nrecursive-include foobar/data *.json\ngraft templates\n",
+        )
+        .expect("Failed to write MANIFEST.in");
+
+        let generated_recipe = PythonGenerator::default()
+            .generate_recipe(
+                &minimal_project(),
+                &PythonBackendConfig::default(),
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build_input_globs = &generated_recipe.build_input_globs;
+        // All globs are relative to the manifest root.
+        for glob in [
+            "pyproject.toml",
+            "MANIFEST.in",
+            "LICENSE",
+            "foobar/data/**/*.json",
+            "templates/**",
+        ] {
+            assert!(build_input_globs.contains(glob), "{build_input_globs:?}");
+        }
+    }
+
+    #[test]
+    fn test_unreadable_manifest_in_is_not_an_error() {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        std::fs::write(temp_dir.path().join("MANIFEST.in"), [0xff, 0xfe, 0xfd])
+            .expect("Failed to write MANIFEST.in");
+
+        let generated_recipe = PythonGenerator::default()
+            .generate_recipe(
+                &minimal_project(),
+                &PythonBackendConfig::default(),
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        assert!(!generated_recipe.build_input_globs.contains("MANIFEST.in"));
+    }
+
+    #[test]
+    fn test_editable_install_follows_python_params() {
+        let generate = |editable: bool| {
+            PythonGenerator::default()
+                .generate_recipe(
+                    &minimal_project(),
+                    &PythonBackendConfig::default_with_ignore_pyproject_manifest(),
+                    PathBuf::from("."),
+                    Platform::Linux64,
+                    Some(PythonParams { editable }),
+                    &HashSet::new(),
+                    vec![],
+                )
+                .expect("Failed to generate recipe")
+                .recipe
+                .build
+                .script
+                .content
+        };
+
+        // An editable install goes through the PEP 660 hooks of the build
+        // backend, against the dependencies that are already in the host
+        // environment.
+        let script = generate(true);
+        assert!(script.contains("--editable \".\""), "{script}");
+        assert!(script.contains("--no-build-isolation"), "{script}");
+        assert!(script.contains("--no-deps"), "{script}");
+
+        let script = generate(false);
+        assert!(!script.contains("--editable"), "{script}");
+    }
 }
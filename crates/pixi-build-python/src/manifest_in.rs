@@ -0,0 +1,91 @@
+//! Reads the files that a setuptools `MANIFEST.in` includes in the source
+//! distribution.
+
+use std::path::Path;
+
+use miette::IntoDiagnostic;
+
+/// Returns the input globs of the files that the `MANIFEST.in` in
+/// `manifest_root` includes, or nothing if there is no `MANIFEST.in`.
+///
+/// The `include`, `recursive-include` and `graft` directives are converted to
+/// globs relative to `manifest_root`. All other directives only remove files,
+/// which can be ignored because the globs are only used to decide whether the
+/// package needs to be rebuilt.
+pub fn parse_manifest_in(manifest_root: &Path) -> miette::Result<Vec<String>> {
+    let manifest_in_path = manifest_root.join("MANIFEST.in");
+    if !manifest_in_path.is_file() {
+        return Ok(Vec::new());
+    }
+    let manifest_in = fs_err::read_to_string(&manifest_in_path).into_diagnostic()?;
+    Ok(input_globs(&manifest_in))
+}
+
+/// Converts the directives of the contents of a `MANIFEST.in` to globs.
+fn input_globs(manifest_in: &str) -> Vec<String> {
+    let mut globs = Vec::new();
+    for line in manifest_in.lines() {
+        let mut words = line.split_whitespace();
+        let Some(directive) = words.next().filter(|word| !word.starts_with('#')) else {
+            continue;
+        };
+        match directive {
+            "include" => globs.extend(words.map(str::to_string)),
+            "recursive-include" => {
+                let Some(dir) = words.next() else {
+                    continue;
+                };
+                let prefix = dir_prefix(dir);
+                globs.extend(words.map(|pattern| format!("{prefix}**/{pattern}")));
+            }
+            "graft" => globs.extend(words.map(|dir| format!("{}**", dir_prefix(dir)))),
+            _ => {}
+        }
+    }
+    globs
+}
+
+/// Returns `dir` with a trailing slash, or nothing for the root directory.
+fn dir_prefix(dir: &str) -> String {
+    let dir = dir.trim_end_matches('/');
+    if dir.is_empty() || dir == "." {
+        String::new()
+    } else {
+        format!("{dir}/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_globs() {
+        let manifest_in = r#"
+# Data files
+include README.md LICENSE
+recursive-include foobar/data *.json *.csv
+recursive-include . *.toml
+graft templates/
+exclude foobar/data/large.json
+prune tests
+"#;
+        assert_eq!(
+            input_globs(manifest_in),
+            vec![
+                "README.md",
+                "LICENSE",
+                "foobar/data/**/*.json",
+                "foobar/data/**/*.csv",
+                "**/*.toml",
+                "templates/**",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_manifest_in() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        assert!(parse_manifest_in(temp_dir.path()).unwrap().is_empty());
+    }
+}
@@ -60,6 +60,27 @@ impl PyprojectMetadataProvider {
         Ok(self.ensure_manifest()?.project.as_ref())
     }
 
+    /// Returns the first URL in the `project.urls` table whose label matches
+    /// one of `labels`.
+    ///
+    /// Labels are compared after normalization as described in PEP 753, so
+    /// `Source Code`, `source-code` and `sourcecode` are all equivalent.
+    /// `labels` are expected to already be normalized.
+    fn project_url(&self, labels: &[&str]) -> Result<Option<String>, MetadataError> {
+        let Some(urls) = self
+            .ensure_manifest_project()?
+            .and_then(|proj| proj.urls.as_ref())
+        else {
+            return Ok(None);
+        };
+
+        Ok(labels.iter().find_map(|label| {
+            urls.iter()
+                .find(|(key, _)| normalize_url_label(key) == *label)
+                .map(|(_, url)| url.clone())
+        }))
+    }
+
     /// Ensures that the manifest is loaded
     fn ensure_manifest(&self) -> Result<&PyProjectToml, MetadataError> {
         self.pyproject_manifest.get_or_try_init(move || {
@@ -146,59 +167,68 @@ impl MetadataProvider for PyprojectMetadataProvider {
     /// Returns the package homepage URL from the pyproject.toml manifest.
     ///
     /// If `ignore_pyproject_manifest` is true, returns `None`. Otherwise, extracts
-    /// the homepage from the project.urls section.
+    /// the homepage from the project.urls section (`Homepage` or `Home`).
     fn homepage(&mut self) -> Result<Option<String>, Self::Error> {
         if self.ignore_pyproject_manifest {
             return Ok(None);
         }
-        Ok(self
-            .ensure_manifest_project()?
-            .and_then(|proj| proj.urls.as_ref())
-            .and_then(|urls| urls.get("Homepage").cloned()))
+        self.project_url(&["homepage", "home"])
     }
 
     /// Returns the package license from the pyproject.toml manifest.
     ///
     /// If `ignore_pyproject_manifest` is true, returns `None`. Otherwise, extracts
     /// the license from the project section. If the license text is not a valid
-    /// SPDX expression, a warning is added and `None` is returned.
+    /// SPDX expression, a warning is added and `None` is returned. A license
+    /// file is identified by its heading. If no license is specified, the
+    /// license is derived from the `License ::` classifiers.
     fn license(&mut self) -> Result<Option<String>, Self::Error> {
         if self.ignore_pyproject_manifest {
             return Ok(None);
         }
-        Ok(self
-            .ensure_manifest_project()?
-            .and_then(|proj| match proj.license.as_ref() {
-                Some(pyproject_toml::License::Spdx(spdx)) => {
-                    match spdx.parse::<spdx::Expression>() {
-                        Ok(expr) => Some(expr.to_string()),
-                        Err(err) => {
-                            self.add_warning(format!(
-                                "License '{}' is not a valid SPDX expression: {}. \
-                                 Consider using a valid SPDX identifier (e.g., 'MIT', 'Apache-2.0'). \
-                                 See <https://spdx.org/licenses> for the list of valid licenses.",
-                                spdx, err
-                            ));
-                            None
-                        }
-                    }
+        let Some(project) = self.ensure_manifest_project()? else {
+            return Ok(None);
+        };
+        if project.license.is_none() {
+            return Ok(project
+                .classifiers
+                .as_deref()
+                .and_then(extract_license_from_classifiers));
+        }
+        Ok(match project.license.as_ref() {
+            Some(pyproject_toml::License::Spdx(spdx)) => match spdx.parse::<spdx::Expression>() {
+                Ok(expr) => Some(expr.to_string()),
+                Err(err) => {
+                    self.add_warning(format!(
+                        "License '{}' is not a valid SPDX expression: {}. \
+                         Consider using a valid SPDX identifier (e.g., 'MIT', 'Apache-2.0'). \
+                         See <https://spdx.org/licenses> for the list of valid licenses.",
+                        spdx, err
+                    ));
+                    None
                 }
-                Some(pyproject_toml::License::Text { text }) => {
-                    match text.parse::<spdx::Expression>() {
-                        Ok(expr) => Some(expr.to_string()),
-                        Err(err) => {
-                            self.add_warning(format!(
-                                "License text '{}' is not a valid SPDX expression: {}. \
-                                 Consider using a valid SPDX identifier (e.g., 'MIT', 'Apache-2.0'). \
-                                 See <https://spdx.org/licenses> for the list of valid licenses.",
-                                text, err
-                            ));
-                            None
-                        }
+            },
+            Some(pyproject_toml::License::Text { text }) => {
+                match text.parse::<spdx::Expression>() {
+                    Ok(expr) => Some(expr.to_string()),
+                    Err(err) => {
+                        self.add_warning(format!(
+                            "License text '{}' is not a valid SPDX expression: {}. \
+                             Consider using a valid SPDX identifier (e.g., 'MIT', 'Apache-2.0'). \
+                             See <https://spdx.org/licenses> for the list of valid licenses.",
+                            text, err
+                        ));
+                        None
                     }
                 }
-                _ => None,
-            }))
+            }
+            Some(pyproject_toml::License::File { file }) => {
+                fs_err::read_to_string(self.manifest_root.join(file))
+                    .ok()
+                    .and_then(|contents| extract_license_from_license_file(&contents))
+            }
+            _ => None,
+        })
     }
 
     /// Returns the package license file path(s) from the pyproject.toml manifest.
@@ -250,46 +280,137 @@ impl MetadataProvider for PyprojectMetadataProvider {
     /// Returns the package documentation URL from the pyproject.toml manifest.
     ///
     /// If `ignore_pyproject_manifest` is true, returns `None`. Otherwise, extracts
-    /// the documentation URL from the project.urls section.
+    /// the documentation URL from the project.urls section (`Documentation` or
+    /// `Docs`).
     fn documentation(&mut self) -> Result<Option<String>, Self::Error> {
         if self.ignore_pyproject_manifest {
             return Ok(None);
         }
-        Ok(self
-            .ensure_manifest_project()?
-            .and_then(|proj| proj.urls.as_ref())
-            .and_then(|urls| {
-                urls.get("Documentation")
-                    .or_else(|| urls.get("Docs"))
-                    .cloned()
-            }))
+        self.project_url(&["documentation", "docs"])
     }
 
     /// Returns the package repository URL from the pyproject.toml manifest.
     ///
     /// If `ignore_pyproject_manifest` is true, returns `None`. Otherwise, extracts
-    /// the repository URL from the project.urls section.
+    /// the repository URL from the project.urls section (`Repository`, `Source`,
+    /// `Source Code` or `Code`).
     fn repository(&mut self) -> Result<Option<String>, Self::Error> {
         if self.ignore_pyproject_manifest {
             return Ok(None);
         }
-        Ok(self
-            .ensure_manifest_project()?
-            .and_then(|proj| proj.urls.as_ref())
-            .and_then(|urls| {
-                urls.get("Repository")
-                    .or_else(|| urls.get("Source"))
-                    .or_else(|| urls.get("Source Code"))
-                    .cloned()
-            }))
+        self.project_url(&["repository", "source", "sourcecode", "code"])
     }
 }
 
+/// Normalizes a `project.urls` label by lowercasing it and removing
+/// punctuation and whitespace, see PEP 753.
+fn normalize_url_label(label: &str) -> String {
+    label
+        .chars()
+        .filter(|c| !c.is_ascii_punctuation() && !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Maps the license part of a trove classifier to an SPDX identifier.
+/// Classifiers that do not determine the license, e.g. the generic
+/// `BSD License`, are not listed.
+const LICENSE_CLASSIFIERS: &[(&str, &str)] = &[
+    ("MIT License", "MIT"),
+    ("Apache Software License", "Apache-2.0"),
+    ("GNU General Public License v2 (GPLv2)", "GPL-2.0-only"),
+    (
+        "GNU General Public License v2 or later (GPLv2+)",
+        "GPL-2.0-or-later",
+    ),
+    ("GNU General Public License v3 (GPLv3)", "GPL-3.0-only"),
+    (
+        "GNU General Public License v3 or later (GPLv3+)",
+        "GPL-3.0-or-later",
+    ),
+    (
+        "GNU Lesser General Public License v2 (LGPLv2)",
+        "LGPL-2.0-only",
+    ),
+    (
+        "GNU Lesser General Public License v2 or later (LGPLv2+)",
+        "LGPL-2.0-or-later",
+    ),
+    (
+        "GNU Lesser General Public License v3 (LGPLv3)",
+        "LGPL-3.0-only",
+    ),
+    (
+        "GNU Lesser General Public License v3 or later (LGPLv3+)",
+        "LGPL-3.0-or-later",
+    ),
+    ("GNU Affero General Public License v3", "AGPL-3.0-only"),
+    (
+        "GNU Affero General Public License v3 or later (AGPLv3+)",
+        "AGPL-3.0-or-later",
+    ),
+    ("Mozilla Public License 2.0 (MPL 2.0)", "MPL-2.0"),
+    ("ISC License (ISCL)", "ISC"),
+    ("Python Software Foundation License", "PSF-2.0"),
+    ("The Unlicense (Unlicense)", "Unlicense"),
+    ("Eclipse Public License 2.0 (EPL-2.0)", "EPL-2.0"),
+    ("Boost Software License 1.0 (BSL-1.0)", "BSL-1.0"),
+    ("zlib/libpng License", "Zlib"),
+];
+
+/// The headings of common license texts and their SPDX identifiers. Licenses
+/// whose text does not determine the identifier, e.g. the GPL which may be
+/// `-only` or `-or-later`, are not listed.
+const LICENSE_FILE_HEADINGS: &[(&str, &str)] = &[
+    ("MIT License", "MIT"),
+    ("Apache License", "Apache-2.0"),
+    ("BSD 2-Clause License", "BSD-2-Clause"),
+    ("BSD 3-Clause License", "BSD-3-Clause"),
+    ("ISC License", "ISC"),
+    ("Mozilla Public License Version 2.0", "MPL-2.0"),
+    (
+        "This is free and unencumbered software released into the public domain.",
+        "Unlicense",
+    ),
+];
+
+/// Infers the SPDX identifier of a license file from its first non-empty
+/// line, or returns `None` if the heading is not known.
+pub fn extract_license_from_license_file(contents: &str) -> Option<String> {
+    let heading = contents
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())?;
+    LICENSE_FILE_HEADINGS
+        .iter()
+        .find(|(known_heading, _)| heading.eq_ignore_ascii_case(known_heading))
+        .map(|(_, spdx)| spdx.to_string())
+}
+
+/// Extracts an SPDX license identifier from a list of trove classifiers, e.g.
+/// `"License :: OSI Approved :: MIT License"` becomes `"MIT"`.
+///
+/// Returns the first classifier that maps to a known license, or `None` if
+/// none of the classifiers describe a known license.
+pub fn extract_license_from_classifiers(classifiers: &[String]) -> Option<String> {
+    classifiers
+        .iter()
+        .filter(|classifier| classifier.starts_with("License ::"))
+        .find_map(|classifier| {
+            let license = classifier.rsplit("::").next()?.trim();
+            LICENSE_CLASSIFIERS
+                .iter()
+                .find(|(name, _)| *name == license)
+                .map(|(_, spdx)| spdx.to_string())
+        })
+}
+
 impl PyprojectMetadataProvider {
     /// Returns the required Python version from the pyproject.toml manifest.
     ///
     /// If `ignore_pyproject_manifest` is true, returns `None`. Otherwise, extracts
-    /// the requires-python from the project section.
+    /// the requires-python from the project section, converted to a conda
+    /// version spec.
     pub fn requires_python(&self) -> Result<Option<String>, MetadataError> {
         if self.ignore_pyproject_manifest {
             return Ok(None);
@@ -297,13 +418,36 @@ impl PyprojectMetadataProvider {
         Ok(self
             .ensure_manifest_project()?
             .and_then(|proj| proj.requires_python.as_ref())
-            .map(|req_py| req_py.to_string()))
+            .map(|req_py| pep440_to_conda_version_spec(&req_py.to_string())))
     }
 }
 
+/// Converts a PEP 440 version specifier, e.g. `>= 3.9, ==3.11.*`, into a conda
+/// version spec.
+///
+/// Whitespace is removed and prefix matches (`==3.11.*`) are written as
+/// `3.11.*`, because conda does not allow `==` to be combined with a glob.
+pub fn pep440_to_conda_version_spec(specifier: &str) -> String {
+    specifier
+        .split(',')
+        .map(|clause| {
+            let clause: String = clause.split_whitespace().collect();
+            match clause.strip_prefix("==") {
+                Some(version) if version.ends_with(".*") => version.to_string(),
+                _ => clause,
+            }
+        })
+        .filter(|clause| !clause.is_empty())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashSet, fs};
+    use std::{
+        collections::{BTreeMap, HashSet},
+        fs,
+    };
 
     use pixi_build_backend::generated_recipe::{GenerateRecipe, MetadataProvider};
     use rattler_conda_types::Platform;
@@ -386,6 +530,36 @@ license = {file = "LICENSE.txt"}
         );
     }
 
+    #[test]
+    fn test_license_forms() {
+        let license = |license: &str, license_file: Option<&str>| {
+            let temp_dir = create_temp_pyproject_project(&format!(
+                "[project]\nname = \"test-package\"\nversion = \"1.0.0\"\nlicense = {license}\n"
+            ));
+            if let Some(contents) = license_file {
+                fs::write(temp_dir.path().join("LICENSE"), contents).unwrap();
+            }
+            create_metadata_provider(temp_dir.path()).license().unwrap()
+        };
+
+        let licenses = BTreeMap::from([
+            ("expression", license(r#""GPL-2.0-or-later""#, None)),
+            ("text", license(r#"{ text = "Apache-2.0" }"#, None)),
+            (
+                "file",
+                license(
+                    r#"{ file = "LICENSE" }"#,
+                    Some("MIT License\n\nCopyright (c) 2024 Example\n"),
+                ),
+            ),
+            (
+                "unknown file",
+                license(r#"{ file = "LICENSE" }"#, Some("All rights reserved.\n")),
+            ),
+        ]);
+        insta::assert_yaml_snapshot!(licenses);
+    }
+
     #[test]
     fn test_license_files_field() {
         let pyproject_toml_content = r#"
@@ -490,6 +664,77 @@ license = {text = "BLABLA"}
         assert!(warnings[0].contains("not a valid SPDX expression"));
     }
 
+    #[test]
+    fn test_license_from_classifiers() {
+        let pyproject_toml_content = r#"
+[project]
+name = "test-package"
+version = "1.0.0"
+classifiers = [
+    "Programming Language :: Python :: 3",
+    "License :: OSI Approved :: Apache Software License",
+]
+"#;
+
+        let temp_dir = create_temp_pyproject_project(pyproject_toml_content);
+        let mut provider = create_metadata_provider(temp_dir.path());
+
+        assert_eq!(provider.license().unwrap(), Some("Apache-2.0".to_string()));
+    }
+
+    #[test]
+    fn test_license_field_takes_precedence_over_classifiers() {
+        let pyproject_toml_content = r#"
+[project]
+name = "test-package"
+version = "1.0.0"
+license = "BSD-2-Clause"
+classifiers = ["License :: OSI Approved :: MIT License"]
+"#;
+
+        let temp_dir = create_temp_pyproject_project(pyproject_toml_content);
+        let mut provider = create_metadata_provider(temp_dir.path());
+
+        assert_eq!(
+            provider.license().unwrap(),
+            Some("BSD-2-Clause".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_license_from_classifiers() {
+        let classifiers =
+            |values: &[&str]| -> Vec<String> { values.iter().map(|s| s.to_string()).collect() };
+
+        assert_eq!(
+            extract_license_from_classifiers(&classifiers(&[
+                "License :: OSI Approved :: MIT License"
+            ])),
+            Some("MIT".to_string())
+        );
+        assert_eq!(
+            extract_license_from_classifiers(&classifiers(&[
+                "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)"
+            ])),
+            Some("GPL-3.0-or-later".to_string())
+        );
+        // The generic BSD classifier does not tell which BSD license is used.
+        assert_eq!(
+            extract_license_from_classifiers(&classifiers(&[
+                "License :: OSI Approved :: BSD License"
+            ])),
+            None
+        );
+        assert_eq!(
+            extract_license_from_classifiers(&classifiers(&[
+                "License :: OSI Approved",
+                "License :: Other/Proprietary License",
+            ])),
+            None
+        );
+        assert_eq!(extract_license_from_classifiers(&[]), None);
+    }
+
     #[test]
     fn test_missing_project_section() {
         let pyproject_toml_content = r#"
@@ -668,6 +913,17 @@ requires-python = ">=3.13"
         );
     }
 
+    #[test]
+    fn test_pep440_to_conda_version_spec() {
+        use super::pep440_to_conda_version_spec;
+
+        assert_eq!(pep440_to_conda_version_spec(">=3.9"), ">=3.9");
+        assert_eq!(pep440_to_conda_version_spec(">= 3.9, <3.13"), ">=3.9,<3.13");
+        assert_eq!(pep440_to_conda_version_spec("==3.11.*"), "3.11.*");
+        assert_eq!(pep440_to_conda_version_spec("==3.11.4"), "==3.11.4");
+        assert_eq!(pep440_to_conda_version_spec("~=3.10"), "~=3.10");
+    }
+
     #[test]
     fn test_requires_python_with_ignore_flag() {
         let pyproject_toml_content = r#"
@@ -736,6 +992,53 @@ Documentation = "https://docs.example.com"
         });
     }
 
+    #[test]
+    fn test_generated_recipe_contains_pyproject_urls() {
+        let pyproject_toml_content = r#"
+[project]
+name = "test-package"
+version = "1.0.0"
+classifiers = ["License :: OSI Approved :: Apache Software License"]
+
+[project.urls]
+homepage = "https://example.com"
+"Source Code" = "https://github.com/example/test-package"
+docs = "https://docs.example.com"
+Changelog = "https://github.com/example/test-package/releases"
+"#;
+
+        let temp_dir = create_temp_pyproject_project(pyproject_toml_content);
+
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "targets": {
+                "defaultTarget": {}
+            }
+        });
+
+        let generated_recipe = PythonGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &PythonBackendConfig::default(),
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        insta::assert_yaml_snapshot!(generated_recipe.recipe.about);
+    }
+
+    #[test]
+    fn test_normalize_url_label() {
+        assert_eq!(normalize_url_label("Homepage"), "homepage");
+        assert_eq!(normalize_url_label("Source Code"), "sourcecode");
+        assert_eq!(normalize_url_label("source-code"), "sourcecode");
+        assert_eq!(normalize_url_label("Source_Code."), "sourcecode");
+    }
+
     #[test]
     fn test_generated_recipe_respects_requires_python() {
         let pyproject_toml_content = r#"
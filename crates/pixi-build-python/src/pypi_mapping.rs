@@ -0,0 +1,115 @@
+use pyproject_toml::PyProjectToml;
+
+use crate::metadata::pep440_to_conda_version_spec;
+
+/// PyPI packages that are published under a different name on conda-forge.
+const PYPI_TO_CONDA_NAMES: &[(&str, &str)] = &[
+    ("torch", "pytorch"),
+    ("tables", "pytables"),
+    ("msgpack", "msgpack-python"),
+    ("opencv-python", "opencv"),
+    ("opencv-python-headless", "opencv"),
+    ("pyqt5", "pyqt"),
+];
+
+/// Maps the requirements of a pyproject.toml to conda package specs.
+///
+/// The name of a PyPI package is normalized and looked up in a small table of
+/// known renames, otherwise the conda package is assumed to have the same
+/// name. Environment markers are not evaluated.
+#[derive(Debug, Default)]
+pub struct PyPiToCondaMapper;
+
+impl PyPiToCondaMapper {
+    /// Returns the conda package name of a PyPI package.
+    pub fn conda_name(&self, pypi_name: &str) -> String {
+        let normalized = pypi_name.to_lowercase().replace(['_', '.'], "-");
+        PYPI_TO_CONDA_NAMES
+            .iter()
+            .find(|(pypi, _)| *pypi == normalized)
+            .map(|(_, conda)| conda.to_string())
+            .unwrap_or(normalized)
+    }
+
+    /// Returns the conda specs of the dependencies of the given
+    /// `project.optional-dependencies` extras, in order and without
+    /// duplicates. Requirements that point at a URL cannot be mapped and are
+    /// skipped.
+    pub fn extra_dependencies(
+        &self,
+        pyproject: &PyProjectToml,
+        extras: &[String],
+    ) -> miette::Result<Vec<String>> {
+        let optional_dependencies = pyproject
+            .project
+            .as_ref()
+            .and_then(|project| project.optional_dependencies.as_ref());
+
+        let mut specs = Vec::new();
+        for extra in extras {
+            let Some(requirements) = optional_dependencies.and_then(|deps| deps.get(extra)) else {
+                miette::bail!(
+                    "the extra `{extra}` is not declared in `project.optional-dependencies` of the pyproject.toml"
+                );
+            };
+
+            for requirement in requirements {
+                let version = match requirement.version_or_url.as_ref().map(ToString::to_string) {
+                    Some(version) if version.trim_start().starts_with('@') => continue,
+                    Some(version) => pep440_to_conda_version_spec(&version),
+                    None => String::new(),
+                };
+                let name = self.conda_name(&requirement.name.to_string());
+                let spec = if version.is_empty() {
+                    name
+                } else {
+                    format!("{name} {version}")
+                };
+                if !specs.contains(&spec) {
+                    specs.push(spec);
+                }
+            }
+        }
+        Ok(specs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conda_name() {
+        let mapper = PyPiToCondaMapper;
+        assert_eq!(mapper.conda_name("Matplotlib"), "matplotlib");
+        assert_eq!(mapper.conda_name("typing_extensions"), "typing-extensions");
+        assert_eq!(mapper.conda_name("torch"), "pytorch");
+    }
+
+    #[test]
+    fn test_extra_dependencies() {
+        let pyproject = toml::from_str::<PyProjectToml>(
+            r#"[project]
+name = "foobar"
+
+[project.optional-dependencies]
+viz = ["matplotlib>=3.5", "plotly"]
+ml = ["torch >=2, <3", "plotly", "foo @ https://example.com/foo.whl"]
+"#,
+        )
+        .unwrap();
+
+        let mapper = PyPiToCondaMapper;
+        assert_eq!(
+            mapper
+                .extra_dependencies(&pyproject, &["viz".to_string(), "ml".to_string()])
+                .unwrap(),
+            vec!["matplotlib >=3.5", "plotly", "pytorch >=2,<3"]
+        );
+        assert!(
+            mapper
+                .extra_dependencies(&pyproject, &["docs".to_string()])
+                .is_err()
+        );
+    }
+}
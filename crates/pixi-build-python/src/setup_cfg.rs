@@ -0,0 +1,91 @@
+//! Reads the entry points of a setuptools `setup.cfg`.
+
+/// Returns the `name = module:function` entries of the `console_scripts` of
+/// the `[options.entry_points]` section of a `setup.cfg`.
+///
+/// Only the subset of the INI syntax that setuptools documents for this
+/// section is supported: the value either follows the key on the same line,
+/// or is continued on indented lines. Lines starting with `#` or `;` are
+/// comments.
+pub fn console_scripts(setup_cfg: &str) -> Vec<String> {
+    let mut scripts = Vec::new();
+    let mut in_entry_points = false;
+    let mut in_console_scripts = false;
+
+    for line in setup_cfg.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+
+        // A section header ends the previous section.
+        if let Some(section) = trimmed
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+        {
+            in_entry_points = section.trim() == "options.entry_points";
+            in_console_scripts = false;
+            continue;
+        }
+        if !in_entry_points {
+            continue;
+        }
+
+        // Indented lines continue the value of the previous key.
+        let is_continuation = line.starts_with(char::is_whitespace);
+        if is_continuation {
+            if in_console_scripts {
+                scripts.push(trimmed.to_string());
+            }
+            continue;
+        }
+
+        let Some((key, value)) = trimmed.split_once(['=', ':']) else {
+            in_console_scripts = false;
+            continue;
+        };
+        in_console_scripts = key.trim() == "console_scripts";
+        if in_console_scripts && !value.trim().is_empty() {
+            scripts.push(value.trim().to_string());
+        }
+    }
+
+    scripts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_console_scripts() {
+        let setup_cfg = r#"
+[metadata]
+name = foobar
+
+[options.entry_points]
+# The command line interface
+console_scripts =
+    foobar = foobar.cli:main
+    foobar-admin = foobar.admin:main
+gui_scripts =
+    foobar-gui = foobar.gui:main
+
+[options]
+packages = find:
+"#;
+        assert_eq!(
+            console_scripts(setup_cfg),
+            vec![
+                "foobar = foobar.cli:main",
+                "foobar-admin = foobar.admin:main"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_console_scripts_on_one_line() {
+        let setup_cfg = "[options.entry_points]\nconsole_scripts = foobar = foobar.cli:main\n";
+        assert_eq!(console_scripts(setup_cfg), vec!["foobar = foobar.cli:main"]);
+    }
+}
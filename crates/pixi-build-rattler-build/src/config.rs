@@ -1,8 +1,9 @@
 use pixi_build_backend::generated_recipe::BackendConfig;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use url::Url;
 
-#[derive(Debug, Default, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 pub struct RattlerBuildBackendConfig {
     /// Deprecated. Setting this has no effect; debug data is always written to
@@ -12,9 +13,34 @@ pub struct RattlerBuildBackendConfig {
     /// Extra input globs to include in addition to the default ones
     #[serde(default)]
     pub extra_input_globs: Vec<String>,
+    /// Globs of input files to exclude from the default and extra input
+    /// globs, e.g. `output/**`. Uses the same glob syntax as the
+    /// input globs.
+    #[serde(default)]
+    pub exclude_input_globs: Vec<String>,
     /// Enable experimental features in rattler-build (e.g., cache support for multi-output recipes)
     #[serde(default)]
     pub experimental: Option<bool>,
+    /// Directory, relative to the manifest, whose subdirectories each contain
+    /// a recipe. Defaults to `recipes` when no recipe is found next to the
+    /// manifest.
+    pub recipe_subdir: Option<PathBuf>,
+    /// Channels to build against when the frontend does not pass any.
+    /// Defaults to `https://prefix.dev/conda-forge`.
+    pub default_channels: Option<Vec<Url>>,
+}
+
+impl Default for RattlerBuildBackendConfig {
+    fn default() -> Self {
+        Self {
+            debug_dir: None,
+            extra_input_globs: Vec::new(),
+            exclude_input_globs: Vec::new(),
+            experimental: None,
+            recipe_subdir: None,
+            default_channels: None,
+        }
+    }
 }
 
 impl BackendConfig for RattlerBuildBackendConfig {
@@ -26,7 +52,10 @@ impl BackendConfig for RattlerBuildBackendConfig {
     /// Target-specific values override base values using the following rules:
     /// - debug_dir: Not allowed to have target specific value
     /// - extra_input_globs: Platform-specific completely replaces base
+    /// - exclude_input_globs: Platform-specific completely replaces base
     /// - experimental: Not allowed to have target specific value
+    /// - recipe_subdir: Not allowed to have target specific value
+    /// - default_channels: Not allowed to have target specific value
     fn merge_with_target_config(&self, target_config: &Self) -> miette::Result<Self> {
         if target_config.debug_dir.is_some() {
             miette::bail!("`debug_dir` cannot have a target specific value");
@@ -36,6 +65,14 @@ impl BackendConfig for RattlerBuildBackendConfig {
             miette::bail!("`experimental` cannot have a target specific value");
         }
 
+        if target_config.recipe_subdir.is_some() {
+            miette::bail!("`recipe_subdir` cannot have a target specific value");
+        }
+
+        if target_config.default_channels.is_some() {
+            miette::bail!("`default_channels` cannot have a target specific value");
+        }
+
         Ok(Self {
             debug_dir: self.debug_dir.clone(),
             extra_input_globs: if target_config.extra_input_globs.is_empty() {
@@ -43,7 +80,14 @@ impl BackendConfig for RattlerBuildBackendConfig {
             } else {
                 target_config.extra_input_globs.clone()
             },
+            exclude_input_globs: if target_config.exclude_input_globs.is_empty() {
+                self.exclude_input_globs.clone()
+            } else {
+                target_config.exclude_input_globs.clone()
+            },
             experimental: self.experimental,
+            recipe_subdir: self.recipe_subdir.clone(),
+            default_channels: self.default_channels.clone(),
         })
     }
 }
@@ -54,6 +98,7 @@ mod tests {
     use pixi_build_backend::generated_recipe::BackendConfig;
     use serde_json::json;
     use std::path::PathBuf;
+    use url::Url;
 
     #[test]
     fn test_ensure_deseralize_from_empty() {
@@ -66,13 +111,19 @@ mod tests {
         let base_config = RattlerBuildBackendConfig {
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             experimental: Some(false),
+            recipe_subdir: None,
+            default_channels: None,
         };
 
         let target_config = RattlerBuildBackendConfig {
             debug_dir: None,
             extra_input_globs: vec!["*.target".to_string()],
+            exclude_input_globs: vec![],
             experimental: None, // Not specified in target
+            recipe_subdir: None,
+            default_channels: None,
         };
 
         let merged = base_config
@@ -94,7 +145,10 @@ mod tests {
         let base_config = RattlerBuildBackendConfig {
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             experimental: Some(true),
+            recipe_subdir: None,
+            default_channels: None,
         };
 
         let empty_target_config = RattlerBuildBackendConfig::default();
@@ -159,6 +213,44 @@ mod tests {
         assert!(error_msg.contains("`experimental` cannot have a target specific value"));
     }
 
+    #[test]
+    fn test_merge_target_recipe_subdir_error() {
+        let base_config = RattlerBuildBackendConfig {
+            recipe_subdir: Some(PathBuf::from("recipes")),
+            ..Default::default()
+        };
+
+        let target_config = RattlerBuildBackendConfig {
+            recipe_subdir: Some(PathBuf::from("other")),
+            ..Default::default()
+        };
+
+        let result = base_config.merge_with_target_config(&target_config);
+        assert!(result.is_err());
+        let error_msg = result.unwrap_err().to_string();
+        assert!(error_msg.contains("`recipe_subdir` cannot have a target specific value"));
+    }
+
+    #[test]
+    fn test_default_channels() {
+        let config: RattlerBuildBackendConfig = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(config.default_channels, None);
+
+        let config: RattlerBuildBackendConfig = serde_json::from_value(json!({
+            "default-channels": ["https://repo.example.com/conda"]
+        }))
+        .unwrap();
+        assert_eq!(
+            config.default_channels,
+            Some(vec![Url::parse("https://repo.example.com/conda").unwrap()])
+        );
+
+        let result = RattlerBuildBackendConfig::default().merge_with_target_config(&config);
+        assert!(result.is_err());
+        let error_msg = result.unwrap_err().to_string();
+        assert!(error_msg.contains("`default_channels` cannot have a target specific value"));
+    }
+
     #[test]
     fn test_merge_experimental_from_base() {
         // Test that experimental value from base config is preserved
@@ -1,12 +1,37 @@
 mod config;
 mod protocol;
 mod rattler_build;
+mod validate;
 
+use std::path::PathBuf;
+
+use clap::Subcommand;
 use protocol::RattlerBuildBackendInstantiator;
 
+/// The subcommands that are specific to this backend.
+#[derive(Subcommand)]
+enum RattlerBuildCommands {
+    /// Validates a recipe without building it.
+    ValidateRecipe {
+        /// The recipe to validate.
+        recipe_path: PathBuf,
+    },
+}
+
 #[tokio::main]
 pub async fn main() {
-    if let Err(err) = pixi_build_backend::cli::main(RattlerBuildBackendInstantiator::new).await {
+    let result = pixi_build_backend::cli::main_with_subcommands(
+        RattlerBuildBackendInstantiator::new,
+        |command| match command {
+            RattlerBuildCommands::ValidateRecipe { recipe_path } => {
+                validate::validate_recipe_file(&recipe_path)
+                    .map(|()| eprintln!("{} is a valid recipe", recipe_path.display()))
+            }
+        },
+    )
+    .await;
+
+    if let Err(err) = result {
         eprintln!("{err:?}");
         std::process::exit(1);
     }
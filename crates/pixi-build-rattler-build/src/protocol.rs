@@ -4,15 +4,20 @@ use std::{
     sync::Arc,
 };
 
-use crate::{config::RattlerBuildBackendConfig, rattler_build::RattlerBuildBackend};
+use crate::{
+    config::RattlerBuildBackendConfig, rattler_build::RattlerBuildBackend,
+    validate::validate_recipe,
+};
 use miette::{Context, IntoDiagnostic};
 use pixi_build_backend::specs_conversion::{
     convert_variant_from_pixi_build_types, convert_variant_to_pixi_build_types,
     from_build_v1_args_to_finalized_dependencies,
 };
 use pixi_build_backend::{
+    consts::DEBUG_OUTPUT_DIR,
     dependencies::{convert_binary_dependencies, convert_dependencies},
     intermediate_backend::{conda_build_v1_directories, find_matching_output},
+    lint::{CondaLintParams, CondaLintResult, LintDiagnostic},
     protocol::{Protocol, ProtocolInstantiator},
     tools::LoadedVariantConfig,
 };
@@ -63,42 +68,79 @@ impl Protocol for RattlerBuildBackend {
     ) -> miette::Result<CondaOutputsResult> {
         let build_platform = params.host_platform;
 
-        // Determine the variant configuration to use. This loads the variant
-        // configuration from disk as well as including the variants from the input
-        // parameters.
-        let selector_config_for_variants = SelectorConfig {
-            target_platform: params.host_platform,
-            host_platform: params.host_platform,
-            build_platform,
-            hash: None,
-            variant: Default::default(),
-            experimental: self.config.experimental.unwrap_or(false),
-            allow_undefined: false,
-            recipe_path: Some(self.recipe_source.path.clone()),
-        };
-        let variant_config = LoadedVariantConfig::from_recipe_path(
-            &self.source_dir,
-            &self.recipe_source.path,
-            &selector_config_for_variants,
-            params.variant_files.iter().flatten().map(PathBuf::as_path),
-        )?
-        .extend_with_input_variants(params.variant_configuration.unwrap_or_default());
-
-        // Find all outputs from the recipe
-        let output_nodes = find_outputs_from_src(self.recipe_source.clone())?;
-        let discovered_outputs = variant_config.variant_config.find_variants(
-            &output_nodes,
-            self.recipe_source.clone(),
-            &selector_config_for_variants,
-        )?;
+        let input_variants = params.variant_configuration.unwrap_or_default();
+
+        // Discover the outputs of every recipe. A multi-recipe directory
+        // contributes the union of the outputs of all its recipes.
+        let mut input_globs = BTreeSet::new();
+        let mut discovered_outputs = Vec::new();
+        for recipe_source in &self.recipe_sources {
+            // Determine the variant configuration to use. This loads the variant
+            // configuration from disk as well as including the variants from the input
+            // parameters.
+            let selector_config_for_variants = SelectorConfig {
+                target_platform: params.host_platform,
+                host_platform: params.host_platform,
+                build_platform,
+                hash: None,
+                variant: Default::default(),
+                experimental: self.config.experimental.unwrap_or(false),
+                allow_undefined: false,
+                recipe_path: Some(recipe_source.path.clone()),
+            };
+
+            // The variant file of the workspace root is used as a fallback, the
+            // variant files of the parameters take precedence over it.
+            let workspace_variant_file = self.workspace_variant_file(&recipe_source.path);
+            if let Some(workspace_variant_file) = &workspace_variant_file {
+                input_globs.insert(build_relative_glob(
+                    &self.manifest_root,
+                    workspace_variant_file,
+                )?);
+            }
+            let variant_config = LoadedVariantConfig::from_recipe_path(
+                &self.source_dir,
+                &recipe_source.path,
+                &selector_config_for_variants,
+                workspace_variant_file
+                    .as_deref()
+                    .filter(|path| path.is_file())
+                    .into_iter()
+                    .chain(params.variant_files.iter().flatten().map(PathBuf::as_path)),
+            )?
+            .extend_with_input_variants(input_variants.clone());
+
+            // Find all outputs from the recipe
+            let output_nodes = find_outputs_from_src(recipe_source.clone())?;
+            let recipe_outputs = variant_config.variant_config.find_variants(
+                &output_nodes,
+                recipe_source.clone(),
+                &selector_config_for_variants,
+            )?;
+
+            input_globs.extend(variant_config.input_globs);
+            input_globs.extend(get_metadata_input_globs(
+                &self.manifest_root,
+                &recipe_source.path,
+            )?);
+
+            discovered_outputs.extend(
+                recipe_outputs
+                    .into_iter()
+                    .map(|output| (recipe_source, selector_config_for_variants.clone(), output)),
+            );
+        }
+
+        // Recipes that are added next to the discovered recipes are new outputs.
+        input_globs.extend(self.recipe_discovery_globs.iter().cloned());
 
         // Construct a mapping that for packages that we want from source.
         //
-        // By default, this includes all the outputs in the recipe. These should all be
+        // By default, this includes all the outputs in the recipes. These should all be
         // build from source, in particular from the current source.
         let mut local_source_packages: HashMap<String, SourcePackageSpecV1> = discovered_outputs
             .iter()
-            .map(|output| {
+            .map(|(_, _, output)| {
                 (
                     output.name.clone(),
                     SourcePackageSpecV1::Path(PathSpecV1 { path: ".".into() }),
@@ -113,7 +155,7 @@ impl Protocol for RattlerBuildBackend {
 
         let mut subpackages = HashMap::new();
         let mut outputs = Vec::new();
-        for discovered_output in discovered_outputs {
+        for (recipe_source, selector_config_for_variants, discovered_output) in discovered_outputs {
             let variant = discovered_output.used_vars;
             let hash = HashInfo::from_variant(&variant, &discovered_output.noarch_type);
 
@@ -123,7 +165,7 @@ impl Protocol for RattlerBuildBackend {
                 variant: variant.clone(),
                 hash: Some(hash.clone()),
                 target_platform: discovered_output.target_platform,
-                ..selector_config_for_variants.clone()
+                ..selector_config_for_variants
             };
 
             // Convert this discovered output into a recipe.
@@ -131,7 +173,7 @@ impl Protocol for RattlerBuildBackend {
                 .map_err(|err| {
                     let errs: ParseErrors<_> = err
                         .into_iter()
-                        .map(|err| ParsingError::from_partial(self.recipe_source.clone(), err))
+                        .map(|err| ParsingError::from_partial(recipe_source.clone(), err))
                         .collect::<Vec<_>>()
                         .into();
                     errs
@@ -146,6 +188,15 @@ impl Protocol for RattlerBuildBackend {
             let build_number = recipe.build().number;
             let build_string = recipe.build().string().resolve(&hash, build_number, &jinja);
 
+            // Write the recipe after the jinja evaluation and variant selection
+            // to help debugging undefined variables or unexpected variants.
+            write_rendered_recipe(
+                &params.work_directory.join(DEBUG_OUTPUT_DIR),
+                &recipe,
+                &build_string,
+            )
+            .await?;
+
             subpackages.insert(
                 recipe.package().name().clone(),
                 PackageIdentifier {
@@ -268,12 +319,6 @@ impl Protocol for RattlerBuildBackend {
             });
         }
 
-        let mut input_globs = variant_config.input_globs;
-        input_globs.extend(get_metadata_input_globs(
-            &self.manifest_root,
-            &self.recipe_source.path,
-        )?);
-
         Ok(CondaOutputsResult {
             outputs,
             input_globs,
@@ -315,22 +360,42 @@ impl Protocol for RattlerBuildBackend {
         // Determine the variant configuration to use. This loads the variant
         // configuration from disk as well as including the variants from the input
         // parameters.
-        let selector_config_for_variants = SelectorConfig {
-            target_platform: host_platform,
-            host_platform,
-            build_platform,
-            hash: None,
-            variant: Default::default(),
-            experimental: self.config.experimental.unwrap_or(false),
-            allow_undefined: false,
-            recipe_path: Some(self.recipe_source.path.clone()),
-        };
-        let outputs = find_outputs_from_src(self.recipe_source.clone())?;
-        let discovered_outputs = variant_config.find_variants(
-            &outputs,
-            self.recipe_source.clone(),
-            &selector_config_for_variants,
-        )?;
+        //
+        // In a multi-recipe directory, the recipe that defines the requested
+        // output is used.
+        let mut matching_recipe = None;
+        for recipe_source in &self.recipe_sources {
+            let selector_config_for_variants = SelectorConfig {
+                target_platform: host_platform,
+                host_platform,
+                build_platform,
+                hash: None,
+                variant: Default::default(),
+                experimental: self.config.experimental.unwrap_or(false),
+                allow_undefined: false,
+                recipe_path: Some(recipe_source.path.clone()),
+            };
+            let outputs = find_outputs_from_src(recipe_source.clone())?;
+            let discovered_outputs = variant_config.find_variants(
+                &outputs,
+                recipe_source.clone(),
+                &selector_config_for_variants,
+            )?;
+            let defines_output = discovered_outputs
+                .iter()
+                .any(|output| output.name == params.output.name.as_normalized());
+            matching_recipe = Some((recipe_source, discovered_outputs));
+            if defines_output {
+                break;
+            }
+        }
+        let (recipe_source, discovered_outputs) =
+            matching_recipe.expect("the backend always has at least one recipe");
+
+        // Report a malformed recipe before any work is done, a failure halfway
+        // through the build is much harder to understand.
+        validate_recipe(recipe_source, host_platform, build_platform)?;
+
         let discovered_output = find_matching_output(&params.output, discovered_outputs)?;
 
         // Set up the proper directories for the build.
@@ -340,7 +405,7 @@ impl Protocol for RattlerBuildBackend {
             params.work_directory,
             self.cache_dir.as_deref(),
             params.output_directory.as_deref(),
-            self.recipe_source.path.clone(),
+            recipe_source.path.clone(),
         );
 
         let tool_config = Configuration::builder()
@@ -369,7 +434,7 @@ impl Protocol for RattlerBuildBackend {
                 hash: discovered_output.hash,
                 variant: discovered_output.used_vars.clone(),
                 directories,
-                channels: vec![],
+                channels: self.channels(params.channels),
                 channel_priority: Default::default(),
                 solve_strategy: Default::default(),
                 timestamp: chrono::Utc::now(),
@@ -407,9 +472,19 @@ impl Protocol for RattlerBuildBackend {
             output_file: output_path,
             input_globs: build_input_globs(
                 &self.manifest_root,
-                &self.recipe_source.path,
+                &recipe_source.path,
                 extract_mutable_package_sources(&output),
-                self.config.extra_input_globs.clone(),
+                self.config
+                    .extra_input_globs
+                    .iter()
+                    .cloned()
+                    .chain(
+                        self.config
+                            .exclude_input_globs
+                            .iter()
+                            .map(|glob| format!("!{glob}")),
+                    )
+                    .collect(),
             )?,
             name: output.name().as_normalized().to_string(),
             version: output.version().clone(),
@@ -417,6 +492,25 @@ impl Protocol for RattlerBuildBackend {
             subdir: *output.target_platform(),
         })
     }
+
+    /// Reports recipes that rattler-build fails to parse.
+    async fn conda_lint(&self, _params: CondaLintParams) -> miette::Result<CondaLintResult> {
+        let diagnostics = self
+            .recipe_sources
+            .iter()
+            .filter_map(|recipe_source| {
+                find_outputs_from_src(recipe_source.clone())
+                    .err()
+                    .map(|err| {
+                        LintDiagnostic::error(
+                            format!("failed to parse recipe: {err}"),
+                            recipe_source.path.clone(),
+                        )
+                    })
+            })
+            .collect();
+        Ok(CondaLintResult { diagnostics })
+    }
 }
 
 /// Extracts the package sources from an `Output` object that are mutable and
@@ -523,6 +617,13 @@ impl ProtocolInstantiator for RattlerBuildBackendInstantiator {
         &self,
         params: InitializeParams,
     ) -> miette::Result<(Box<dyn Protocol + Send + Sync + 'static>, InitializeResult)> {
+        if let Some(path) = Self::debug_dir(params.configuration.clone()) {
+            warn!(
+                path = %path.display(),
+                "`debug-dir` backend configuration is deprecated and ignored; debug data is now written to the build work directory."
+            );
+        }
+
         let config = if let Some(config) = params.configuration {
             serde_json::from_value(config)
                 .into_diagnostic()
@@ -531,13 +632,6 @@ impl ProtocolInstantiator for RattlerBuildBackendInstantiator {
             RattlerBuildBackendConfig::default()
         };
 
-        if let Some(path) = config.debug_dir.as_ref() {
-            warn!(
-                path = %path.display(),
-                "`debug-dir` backend configuration is deprecated and ignored; debug data is now written to the build work directory."
-            );
-        }
-
         let mut workspace_dependencies = HashMap::new();
 
         if let Some(target) = params
@@ -598,6 +692,7 @@ impl ProtocolInstantiator for RattlerBuildBackendInstantiator {
 
         // Set the workspace dependencies
         instance.workspace_dependencies = workspace_dependencies;
+        instance.workspace_root = params.workspace_root;
 
         Ok((Box::new(instance), InitializeResult {}))
     }
@@ -611,6 +706,34 @@ impl ProtocolInstantiator for RattlerBuildBackendInstantiator {
     }
 }
 
+/// Writes the rendered recipe of an output to
+/// `rendered_recipe_<output_name>-<build_string>.json` in the debug directory.
+/// The build string contains the variant hash, so every variant of an output
+/// gets its own file.
+async fn write_rendered_recipe(
+    debug_dir: &Path,
+    recipe: &Recipe,
+    build_string: &str,
+) -> miette::Result<()> {
+    tokio::fs::create_dir_all(debug_dir)
+        .await
+        .into_diagnostic()
+        .context("failed to create debug directory")?;
+
+    let json = serde_json::to_string_pretty(recipe)
+        .into_diagnostic()
+        .context("failed to serialize the rendered recipe")?;
+
+    let path = debug_dir.join(format!(
+        "rendered_recipe_{}-{build_string}.json",
+        recipe.package().name().as_normalized()
+    ));
+    tokio::fs::write(&path, json)
+        .await
+        .into_diagnostic()
+        .with_context(|| format!("failed to write {}", path.display()))
+}
+
 pub(crate) fn default_capabilities() -> BackendCapabilities {
     BackendCapabilities {
         provides_conda_outputs: Some(true),
@@ -628,6 +751,7 @@ mod tests {
     use pixi_build_backend::utils::test::conda_outputs_snapshot;
     use pixi_build_types::{VariantValue, procedures::initialize::InitializeParams};
     use rattler_build::console_utils::LoggingOutputHandler;
+    use rattler_conda_types::ChannelUrl;
     use tempfile::tempdir;
 
     use super::*;
@@ -739,6 +863,120 @@ mod tests {
         assert_eq!(python_value, &VariantValue::from("3.9"));
     }
 
+    #[tokio::test]
+    async fn test_workspace_conda_build_config_is_discovered() {
+        let workspace_root = tempdir().unwrap();
+        tokio::fs::write(
+            workspace_root.path().join("conda_build_config.yaml"),
+            r#"python:
+  - "3.12"
+"#,
+        )
+        .await
+        .expect("Failed to write conda_build_config.yaml");
+
+        let package_dir = workspace_root.path().join("packages").join("variant-test");
+        tokio::fs::create_dir_all(&package_dir).await.unwrap();
+        let recipe_path = package_dir.join("recipe.yaml");
+        tokio::fs::write(&recipe_path, VARIANT_RECIPE)
+            .await
+            .expect("Failed to write variant recipe");
+
+        let factory = RattlerBuildBackendInstantiator::new(LoggingOutputHandler::default())
+            .initialize(InitializeParams {
+                workspace_root: Some(workspace_root.path().to_path_buf()),
+                source_dir: None,
+                manifest_path: recipe_path,
+                project_model: None,
+                configuration: None,
+                target_configuration: None,
+                cache_directory: None,
+            })
+            .await
+            .unwrap();
+
+        let result = factory
+            .0
+            .conda_outputs(CondaOutputsParams {
+                channels: vec![],
+                host_platform: Platform::Linux64,
+                build_platform: Platform::Linux64,
+                variant_configuration: None,
+                variant_files: None,
+                work_directory: package_dir.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.outputs.len(), 1);
+        assert_eq!(
+            result.outputs[0].metadata.variant["python"],
+            VariantValue::from("3.12"),
+            "Python variant should come from the workspace conda_build_config.yaml"
+        );
+        assert!(
+            result.input_globs.contains("../../conda_build_config.yaml"),
+            "{:?}",
+            result.input_globs
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rendered_recipe_is_written_to_debug_dir() {
+        let temp_dir = tempdir().unwrap();
+        let recipe_path = temp_dir.path().join("recipe.yaml");
+        tokio::fs::write(&recipe_path, VARIANT_RECIPE)
+            .await
+            .expect("Failed to write variant recipe");
+
+        let mut variant_configuration = BTreeMap::new();
+        variant_configuration.insert(
+            "python".to_string(),
+            vec![VariantValue::from("3.11"), VariantValue::from("3.12")],
+        );
+
+        let factory = RattlerBuildBackendInstantiator::new(LoggingOutputHandler::default())
+            .initialize(InitializeParams {
+                workspace_root: None,
+                source_dir: None,
+                manifest_path: recipe_path,
+                project_model: None,
+                configuration: None,
+                target_configuration: None,
+                cache_directory: None,
+            })
+            .await
+            .unwrap();
+
+        let work_directory = temp_dir.path().join("work");
+        let result = factory
+            .0
+            .conda_outputs(CondaOutputsParams {
+                channels: vec![],
+                host_platform: Platform::Linux64,
+                build_platform: Platform::Linux64,
+                variant_configuration: Some(variant_configuration),
+                variant_files: None,
+                work_directory: work_directory.clone(),
+            })
+            .await
+            .unwrap();
+
+        // Every variant has its own rendered recipe.
+        assert_eq!(result.outputs.len(), 2);
+        for output in &result.outputs {
+            let rendered_recipe_path = work_directory.join(DEBUG_OUTPUT_DIR).join(format!(
+                "rendered_recipe_variant-test-{}.json",
+                output.metadata.build
+            ));
+            let contents = fs_err::read_to_string(&rendered_recipe_path)
+                .expect("the rendered recipe should be written to the debug directory");
+            let rendered_recipe: serde_json::Value =
+                serde_json::from_str(&contents).expect("the rendered recipe should be valid JSON");
+            assert_eq!(rendered_recipe["package"]["name"], "variant-test");
+        }
+    }
+
     #[tokio::test]
     async fn test_variant_configuration_is_applied() {
         let temp_dir = tempdir().unwrap();
@@ -953,12 +1191,12 @@ numpy:
             try_initialize(&tmp.path().join("pixi.toml"))
                 .await
                 .unwrap()
-                .recipe_source
+                .recipe_sources[0]
                 .path,
             recipe
         );
         assert_eq!(
-            try_initialize(&recipe).await.unwrap().recipe_source.path,
+            try_initialize(&recipe).await.unwrap().recipe_sources[0].path,
             recipe
         );
 
@@ -969,12 +1207,12 @@ numpy:
             try_initialize(&tmp.path().join("pixi.toml"))
                 .await
                 .unwrap()
-                .recipe_source
+                .recipe_sources[0]
                 .path,
             recipe
         );
         assert_eq!(
-            try_initialize(&recipe).await.unwrap().recipe_source.path,
+            try_initialize(&recipe).await.unwrap().recipe_sources[0].path,
             recipe
         );
 
@@ -987,7 +1225,7 @@ numpy:
             try_initialize(&tmp.path().join("pixi.toml"))
                 .await
                 .unwrap()
-                .recipe_source
+                .recipe_sources[0]
                 .path,
             recipe
         );
@@ -1001,12 +1239,168 @@ numpy:
             try_initialize(&tmp.path().join("pixi.toml"))
                 .await
                 .unwrap()
-                .recipe_source
+                .recipe_sources[0]
                 .path,
             recipe
         );
     }
 
+    #[tokio::test]
+    async fn test_lint_reports_invalid_recipes() {
+        let lint = |recipe: &'static str| async move {
+            let tmp = tempdir().unwrap();
+            let recipe_path = tmp.path().join("recipe.yaml");
+            std::fs::write(&recipe_path, recipe).unwrap();
+            let backend = try_initialize(&recipe_path).await.unwrap();
+            let result = backend
+                .conda_lint(CondaLintParams {
+                    manifest_path: recipe_path.clone(),
+                    lint_config: None,
+                })
+                .await
+                .unwrap();
+            (recipe_path, result.diagnostics)
+        };
+
+        let (_, diagnostics) = lint(FAKE_RECIPE).await;
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+        let (recipe_path, diagnostics) = lint("package:\n  name: [foobar\n").await;
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].file.as_ref(), Some(&recipe_path));
+    }
+
+    #[tokio::test]
+    async fn test_multi_recipe_discovery() {
+        let tmp = tempdir().unwrap();
+        let recipes_dir = tmp.path().join("recipes");
+        let foo = recipes_dir.join("foo").join("recipe.yaml");
+        let bar = recipes_dir.join("bar").join("recipe.yml");
+        std::fs::create_dir_all(foo.parent().unwrap()).unwrap();
+        std::fs::create_dir_all(bar.parent().unwrap()).unwrap();
+        std::fs::create_dir_all(recipes_dir.join("empty")).unwrap();
+        std::fs::write(&foo, FAKE_RECIPE).unwrap();
+        std::fs::write(&bar, FAKE_RECIPE).unwrap();
+
+        let backend = try_initialize(&tmp.path().join("pixi.toml")).await.unwrap();
+        let recipe_paths = backend
+            .recipe_sources
+            .iter()
+            .map(|source| source.path.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(recipe_paths, vec![bar, foo]);
+        assert_eq!(
+            backend.recipe_discovery_globs,
+            vec!["recipes/*/recipe.{yaml,yml}".to_string()]
+        );
+
+        // A recipe next to the manifest takes precedence over `recipes/`.
+        let recipe = tmp.path().join("recipe.yaml");
+        std::fs::write(&recipe, FAKE_RECIPE).unwrap();
+        let backend = try_initialize(&tmp.path().join("pixi.toml")).await.unwrap();
+        assert_eq!(backend.recipe_sources.len(), 1);
+        assert_eq!(backend.recipe_sources[0].path, recipe);
+        assert!(backend.recipe_discovery_globs.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recipe_subdir_overrides_search_path() {
+        let tmp = tempdir().unwrap();
+        std::fs::write(tmp.path().join("recipe.yaml"), FAKE_RECIPE).unwrap();
+        let recipe = tmp.path().join("packages").join("foo").join("recipe.yaml");
+        std::fs::create_dir_all(recipe.parent().unwrap()).unwrap();
+        std::fs::write(&recipe, FAKE_RECIPE).unwrap();
+
+        let backend = RattlerBuildBackend::new(
+            None,
+            &tmp.path().join("pixi.toml"),
+            LoggingOutputHandler::default(),
+            None,
+            RattlerBuildBackendConfig {
+                recipe_subdir: Some(PathBuf::from("packages")),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(backend.recipe_sources.len(), 1);
+        assert_eq!(backend.recipe_sources[0].path, recipe);
+        assert_eq!(
+            backend.recipe_discovery_globs,
+            vec!["packages/*/recipe.{yaml,yml}".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_channels_are_used_without_channels() {
+        let tmp = tempdir().unwrap();
+        std::fs::write(tmp.path().join("recipe.yaml"), FAKE_RECIPE).unwrap();
+        let default_channel = url::Url::parse("https://repo.example.com/conda").unwrap();
+
+        let backend = RattlerBuildBackend::new(
+            None,
+            &tmp.path().join("pixi.toml"),
+            LoggingOutputHandler::default(),
+            None,
+            RattlerBuildBackendConfig {
+                default_channels: Some(vec![default_channel.clone()]),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            backend.channels(vec![]),
+            vec![ChannelUrl::from(default_channel)]
+        );
+
+        let requested = ChannelUrl::from(url::Url::parse("https://prefix.dev/bioconda").unwrap());
+        assert_eq!(backend.channels(vec![requested.clone()]), vec![requested]);
+
+        let backend = try_initialize(&tmp.path().join("pixi.toml")).await.unwrap();
+        assert_eq!(
+            backend.channels(vec![]),
+            vec![ChannelUrl::from(
+                url::Url::parse("https://prefix.dev/conda-forge").unwrap()
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_multi_recipe_outputs_are_combined() {
+        let tmp = tempdir().unwrap();
+        for name in ["foo", "bar"] {
+            let recipe_dir = tmp.path().join("recipes").join(name);
+            std::fs::create_dir_all(&recipe_dir).unwrap();
+            std::fs::write(
+                recipe_dir.join("recipe.yaml"),
+                format!("package:\n  name: {name}\n  version: 0.1.0\n"),
+            )
+            .unwrap();
+        }
+
+        let backend = try_initialize(&tmp.path().join("pixi.toml")).await.unwrap();
+        let result = backend
+            .conda_outputs(CondaOutputsParams {
+                channels: vec![],
+                host_platform: Platform::Linux64,
+                build_platform: Platform::Linux64,
+                variant_configuration: None,
+                variant_files: None,
+                work_directory: tmp.path().join("work"),
+            })
+            .await
+            .unwrap();
+
+        let names = result
+            .outputs
+            .iter()
+            .map(|output| output.metadata.name.as_normalized().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["bar", "foo"]);
+        assert!(result.input_globs.contains("recipes/foo/recipe.yaml"));
+        assert!(result.input_globs.contains("recipes/bar/recipe.yaml"));
+    }
+
     #[test]
     fn test_relative_path_joined() {
         use std::path::Path;
@@ -5,23 +5,42 @@ use std::{
 };
 
 use miette::IntoDiagnostic;
-use pixi_build_backend::source::Source;
+use pixi_build_backend::{source::Source, tools::VARIANTS_CONFIG_FILE};
 use pixi_build_types::SourcePackageSpecV1;
 use rattler_build::console_utils::LoggingOutputHandler;
+use rattler_conda_types::ChannelUrl;
 
 use crate::config::RattlerBuildBackendConfig;
 
+/// The directory that is searched for recipes in subdirectories when no
+/// recipe is found next to the manifest.
+const DEFAULT_RECIPE_SUBDIR: &str = "recipes";
+
+/// The variant file that is looked up in the workspace root when there is no
+/// variant file next to the recipe.
+const CONDA_BUILD_CONFIG_FILE: &str = "conda_build_config.yaml";
+
+/// The channel that is used when neither the frontend nor the configuration
+/// specifies one.
+const DEFAULT_CHANNEL: &str = "https://prefix.dev/conda-forge";
+
 pub struct RattlerBuildBackend {
     pub(crate) logging_output_handler: LoggingOutputHandler,
     pub(crate) source_dir: PathBuf,
     /// In case of rattler-build, manifest is the raw recipe
-    /// We need to apply later the selectors to get the final recipe
-    pub(crate) recipe_source: Source,
+    /// We need to apply later the selectors to get the final recipe.
+    /// A multi-recipe directory yields one source per recipe.
+    pub(crate) recipe_sources: Vec<Source>,
     pub(crate) manifest_root: PathBuf,
     pub(crate) cache_dir: Option<PathBuf>,
     pub(crate) config: RattlerBuildBackendConfig,
     /// Workspace dependencies from the project model
     pub(crate) workspace_dependencies: HashMap<String, SourcePackageSpecV1>,
+    /// The root of the pixi workspace, if it is known
+    pub(crate) workspace_root: Option<PathBuf>,
+    /// Globs, relative to the manifest root, of the recipes that were searched
+    /// for in subdirectories, so that adding a recipe is picked up.
+    pub(crate) recipe_discovery_globs: Vec<String>,
 }
 
 impl RattlerBuildBackend {
@@ -36,7 +55,7 @@ impl RattlerBuildBackend {
     ) -> miette::Result<Self> {
         // Locate the recipe
         let manifest_file_name = manifest_path.file_name().and_then(OsStr::to_str);
-        let (recipe_path, source_dir) = match manifest_file_name {
+        let (recipe_paths, recipe_discovery_globs, source_dir) = match manifest_file_name {
             Some("recipe.yaml") | Some("recipe.yml") => {
                 let source_dir = source_dir.unwrap_or_else(|| {
                     manifest_path
@@ -44,7 +63,7 @@ impl RattlerBuildBackend {
                         .expect("file always has parent")
                         .to_path_buf()
                 });
-                (manifest_path.to_path_buf(), source_dir)
+                (vec![manifest_path.to_path_buf()], Vec::new(), source_dir)
             }
             _ => {
                 // The manifest is not a recipe, so we need to find the recipe.yaml file.
@@ -54,21 +73,28 @@ impl RattlerBuildBackend {
                         .unwrap_or(manifest_path)
                         .to_path_buf()
                 });
-                let recipe_path = manifest_path.parent().and_then(|manifest_dir| {
-                    [
-                        "recipe.yaml",
-                        "recipe.yml",
-                        "recipe/recipe.yaml",
-                        "recipe/recipe.yml",
-                    ]
-                    .into_iter()
-                    .find_map(|relative_path| {
-                        let recipe_path = manifest_dir.join(relative_path);
-                        recipe_path.is_file().then_some(recipe_path)
-                    })
-                });
+                let manifest_dir = manifest_path.parent().unwrap_or(manifest_path);
+                let (recipe_paths, recipe_discovery_globs) = match &config.recipe_subdir {
+                    Some(recipe_subdir) => (
+                        find_recipes_in_subdirs(&manifest_dir.join(recipe_subdir))?,
+                        vec![subdir_recipes_glob(recipe_subdir)],
+                    ),
+                    None => match find_adjacent_recipe(manifest_dir) {
+                        Some(recipe_path) => (vec![recipe_path], Vec::new()),
+                        None => (
+                            find_recipes_in_subdirs(&manifest_dir.join(DEFAULT_RECIPE_SUBDIR))?,
+                            vec![subdir_recipes_glob(Path::new(DEFAULT_RECIPE_SUBDIR))],
+                        ),
+                    },
+                };
+
+                if recipe_paths.is_empty() {
+                    miette::bail!(
+                        "Could not find a recipe.yaml in the source directory to use as the recipe manifest."
+                    );
+                }
 
-                (recipe_path.ok_or_else(|| miette::miette!("Could not find a recipe.yaml in the source directory to use as the recipe manifest."))?, source_dir)
+                (recipe_paths, recipe_discovery_globs, source_dir)
             }
         };
 
@@ -77,17 +103,104 @@ impl RattlerBuildBackend {
             .parent()
             .expect("manifest must have a root")
             .to_path_buf();
-        let recipe_source =
-            Source::from_rooted_path(&manifest_root, recipe_path).into_diagnostic()?;
+        let recipe_sources = recipe_paths
+            .into_iter()
+            .map(|recipe_path| Source::from_rooted_path(&manifest_root, recipe_path))
+            .collect::<Result<Vec<_>, _>>()
+            .into_diagnostic()?;
 
         Ok(Self {
             logging_output_handler,
             source_dir,
-            recipe_source,
+            recipe_sources,
             manifest_root,
             cache_dir,
             config,
             workspace_dependencies: HashMap::new(),
+            workspace_root: None,
+            recipe_discovery_globs,
         })
     }
+
+    /// Returns the `conda_build_config.yaml` in the workspace root that is
+    /// used if there is no `variants.yaml` next to the recipe. The path is
+    /// returned even if the file does not exist, so that adding it can be
+    /// tracked as an input.
+    pub(crate) fn workspace_variant_file(&self, recipe_path: &Path) -> Option<PathBuf> {
+        let recipe_dir = recipe_path.parent()?;
+        if recipe_dir.join(VARIANTS_CONFIG_FILE).is_file() {
+            return None;
+        }
+
+        Some(self.workspace_root.as_ref()?.join(CONDA_BUILD_CONFIG_FILE))
+    }
+
+    /// Returns the channels to build against. If the frontend did not pass
+    /// any, the configured default channels are used, or conda-forge if none
+    /// are configured.
+    pub(crate) fn channels(&self, channels: Vec<ChannelUrl>) -> Vec<ChannelUrl> {
+        if !channels.is_empty() {
+            return channels;
+        }
+        match &self.config.default_channels {
+            Some(default_channels) => default_channels
+                .iter()
+                .cloned()
+                .map(ChannelUrl::from)
+                .collect(),
+            None => vec![ChannelUrl::from(
+                url::Url::parse(DEFAULT_CHANNEL).expect("the default channel is a valid url"),
+            )],
+        }
+    }
+}
+
+/// Returns the recipe next to the manifest or in the `recipe` directory.
+fn find_adjacent_recipe(manifest_dir: &Path) -> Option<PathBuf> {
+    [
+        "recipe.yaml",
+        "recipe.yml",
+        "recipe/recipe.yaml",
+        "recipe/recipe.yml",
+    ]
+    .into_iter()
+    .find_map(|relative_path| {
+        let recipe_path = manifest_dir.join(relative_path);
+        recipe_path.is_file().then_some(recipe_path)
+    })
+}
+
+/// Returns the `*/recipe.yaml` (or `*/recipe.yml`) files in `recipes_dir`,
+/// sorted by path. Returns an empty list if the directory does not exist.
+fn find_recipes_in_subdirs(recipes_dir: &Path) -> miette::Result<Vec<PathBuf>> {
+    if !recipes_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut recipe_paths = Vec::new();
+    for entry in fs_err::read_dir(recipes_dir).into_diagnostic()? {
+        let path = entry.into_diagnostic()?.path();
+        if let Some(recipe_path) = ["recipe.yaml", "recipe.yml"]
+            .into_iter()
+            .map(|file_name| path.join(file_name))
+            .find(|recipe_path| recipe_path.is_file())
+        {
+            recipe_paths.push(recipe_path);
+        }
+    }
+    recipe_paths.sort();
+
+    Ok(recipe_paths)
+}
+
+/// Returns the glob that matches the recipes [`find_recipes_in_subdirs`] finds
+/// in `recipes_dir`.
+fn subdir_recipes_glob(recipes_dir: &Path) -> String {
+    format!(
+        "{}/*/recipe.{{yaml,yml}}",
+        recipes_dir
+            .to_string_lossy()
+            .replace('\\', "/")
+            .trim_end_matches('/')
+    )
 }
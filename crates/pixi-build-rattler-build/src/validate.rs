@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use miette::IntoDiagnostic;
+use pixi_build_backend::source::Source;
+use rattler_build::{
+    recipe::{ParsingError, Recipe, parser::find_outputs_from_src},
+    selectors::SelectorConfig,
+    variant_config::ParseErrors,
+};
+use rattler_conda_types::Platform;
+
+/// Checks that the recipe of `source` is valid YAML and matches the recipe
+/// schema, without resolving variants or building it. All parse errors of all
+/// outputs are reported together, each with its location in the recipe.
+///
+/// Selectors are evaluated for `host_platform` and `build_platform`.
+/// Variables that are only known once the variants are resolved, e.g.
+/// `${{ python }}`, are allowed to be undefined.
+pub fn validate_recipe(
+    source: &Source,
+    host_platform: Platform,
+    build_platform: Platform,
+) -> miette::Result<()> {
+    let outputs = find_outputs_from_src(source.clone())?;
+
+    let selector_config = SelectorConfig {
+        target_platform: host_platform,
+        host_platform,
+        build_platform,
+        hash: None,
+        variant: Default::default(),
+        experimental: true,
+        allow_undefined: true,
+        recipe_path: Some(source.path.clone()),
+    };
+
+    let errors = outputs
+        .iter()
+        .filter_map(|output| Recipe::from_node(output, selector_config.clone()).err())
+        .flatten()
+        .map(|err| ParsingError::from_partial(source.clone(), err))
+        .collect::<Vec<_>>();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ParseErrors::from(errors).into())
+    }
+}
+
+/// Validates the recipe at `recipe_path` for the current platform, see
+/// [`validate_recipe`].
+pub fn validate_recipe_file(recipe_path: &Path) -> miette::Result<()> {
+    let root_dir = recipe_path.parent().unwrap_or(recipe_path);
+    let source = Source::from_rooted_path(root_dir, recipe_path.to_path_buf()).into_diagnostic()?;
+    validate_recipe(&source, Platform::current(), Platform::current())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate(recipe: &str) -> miette::Result<()> {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let recipe_path = temp_dir.path().join("recipe.yaml");
+        fs_err::write(&recipe_path, recipe).unwrap();
+        validate_recipe_file(&recipe_path)
+    }
+
+    #[test]
+    fn test_valid_recipe() {
+        validate(
+            r#"
+package:
+  name: foobar
+  version: 0.1.0
+
+requirements:
+  host:
+    - python ${{ python }}
+"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_selectors_use_the_given_platforms() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let recipe_path = temp_dir.path().join("recipe.yaml");
+        fs_err::write(
+            &recipe_path,
+            r#"
+package:
+  name: foobar
+  version: 0.1.0
+
+requirements:
+  host:
+    - if: win
+      then: python >>3
+"#,
+        )
+        .unwrap();
+        let source = Source::from_rooted_path(temp_dir.path(), recipe_path).unwrap();
+
+        validate_recipe(&source, Platform::Linux64, Platform::Linux64).unwrap();
+        assert!(validate_recipe(&source, Platform::Win64, Platform::Linux64).is_err());
+    }
+
+    #[test]
+    fn test_invalid_yaml() {
+        assert!(validate("package:\n  name: [foobar\n").is_err());
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        assert!(
+            validate(
+                r#"
+package:
+  name: foobar
+  version: 0.1.0
+
+build:
+  numbr: 0
+
+requirements:
+  hots:
+    - python
+"#,
+            )
+            .is_err()
+        );
+    }
+}
@@ -9,6 +9,12 @@ pub struct BuildScriptContext {
     /// Any additional args to pass to `cargo`
     pub extra_args: Vec<String>,
 
+    /// The cargo features to enable
+    pub features: Vec<String>,
+
+    /// True if the default features of the package should not be enabled
+    pub no_default_features: bool,
+
     /// True if `openssl` is part of the build environment
     pub has_openssl: bool,
 
@@ -17,6 +23,37 @@ pub struct BuildScriptContext {
 
     /// The platform that is running the build.
     pub is_bash: bool,
+
+    /// The rust target triple to compile for when cross-compiling. The linker
+    /// for the target is set to the `$CC` wrapper of the conda compiler,
+    /// except for msvc targets where `$CC` is `cl.exe` and rustc finds
+    /// `link.exe` itself.
+    pub target_triple: Option<String>,
+
+    /// The toolchain channel of the project, exported as `RUSTUP_TOOLCHAIN`
+    /// so a `rustup` installation selects the pinned toolchain.
+    pub rustup_toolchain: Option<String>,
+
+    /// True if the tests should be run with `cargo nextest` before the
+    /// package is installed
+    pub use_nextest: bool,
+
+    /// True if the package should be built with `wasm-pack` for the
+    /// `wasm32-unknown-unknown` target instead of being installed with
+    /// `cargo install`
+    pub wasm: bool,
+
+    /// The `Cargo.lock` to check for dependencies with known vulnerabilities
+    /// with `cargo audit` before the package is built, if any
+    pub audit_lockfile: Option<String>,
+
+    /// The kind of warnings `cargo audit` should treat as errors
+    /// (`--deny`), vulnerabilities are always an error
+    pub audit_deny: Option<String>,
+
+    /// True if the `cargo metadata` of the package should be written to
+    /// `share/pixi-build` as build provenance
+    pub emit_cargo_metadata: bool,
 }
 
 impl BuildScriptContext {
@@ -31,16 +68,37 @@ impl BuildScriptContext {
 
 #[cfg(test)]
 mod test {
+    use rattler_conda_types::Platform;
     use rstest::*;
 
+    use crate::target::platform_to_rust_triple;
+
+    impl Default for super::BuildScriptContext {
+        fn default() -> Self {
+            Self {
+                source_dir: String::from("my-prefix-dir"),
+                extra_args: vec![],
+                features: vec![],
+                no_default_features: false,
+                has_openssl: false,
+                has_sccache: false,
+                is_bash: true,
+                target_triple: None,
+                rustup_toolchain: None,
+                use_nextest: false,
+                wasm: false,
+                audit_lockfile: None,
+                audit_deny: None,
+                emit_cargo_metadata: false,
+            }
+        }
+    }
+
     #[rstest]
     fn test_build_script(#[values(true, false)] is_bash: bool) {
         let context = super::BuildScriptContext {
-            source_dir: String::from("my-prefix-dir"),
-            extra_args: vec![],
-            has_openssl: false,
-            has_sccache: false,
             is_bash,
+            ..Default::default()
         };
         let script = context.render();
 
@@ -54,11 +112,9 @@ mod test {
     #[rstest]
     fn test_sccache(#[values(true, false)] is_bash: bool) {
         let context = super::BuildScriptContext {
-            source_dir: String::from("my-prefix-dir"),
-            extra_args: vec![],
-            has_openssl: false,
             has_sccache: true,
             is_bash,
+            ..Default::default()
         };
         let script = context.render();
 
@@ -72,11 +128,114 @@ mod test {
     #[rstest]
     fn test_openssl(#[values(true, false)] is_bash: bool) {
         let context = super::BuildScriptContext {
-            source_dir: String::from("my-prefix-dir"),
-            extra_args: vec![],
             has_openssl: true,
-            has_sccache: false,
             is_bash,
+            ..Default::default()
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(if is_bash { "bash" } else { "cmdexe" });
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    #[case("features", vec!["serde", "async-std"], false)]
+    #[case("no-default-features", vec![], true)]
+    #[case("features-and-no-default-features", vec!["serde", "async-std"], true)]
+    fn test_cargo_features(
+        #[case] name: &str,
+        #[case] features: Vec<&str>,
+        #[case] no_default_features: bool,
+    ) {
+        let context = super::BuildScriptContext {
+            features: features.into_iter().map(String::from).collect(),
+            no_default_features,
+            ..Default::default()
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(name);
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_cross_compile(
+        #[values(Platform::LinuxAarch64, Platform::OsxArm64, Platform::Win64)]
+        host_platform: Platform,
+    ) {
+        let context = super::BuildScriptContext {
+            target_triple: platform_to_rust_triple(host_platform),
+            ..Default::default()
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(host_platform.as_str());
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_rustup_toolchain(#[values(true, false)] is_bash: bool) {
+        let context = super::BuildScriptContext {
+            is_bash,
+            rustup_toolchain: Some(String::from("1.78.0")),
+            ..Default::default()
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(if is_bash { "bash" } else { "cmdexe" });
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_nextest(#[values(true, false)] is_bash: bool) {
+        let context = super::BuildScriptContext {
+            features: vec![String::from("serde")],
+            is_bash,
+            use_nextest: true,
+            ..Default::default()
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(if is_bash { "bash" } else { "cmdexe" });
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[test]
+    fn test_nextest_is_skipped_when_cross_compiling() {
+        let context = super::BuildScriptContext {
+            is_bash: true,
+            target_triple: Some(String::from("aarch64-unknown-linux-gnu")),
+            use_nextest: true,
+            ..Default::default()
+        };
+        let script = context.render();
+        assert!(!script.contains("cargo nextest run"));
+        assert!(script.contains("--target aarch64-unknown-linux-gnu"));
+    }
+
+    #[rstest]
+    fn test_wasm(#[values(true, false)] is_bash: bool) {
+        let context = super::BuildScriptContext {
+            features: vec![String::from("serde")],
+            is_bash,
+            target_triple: Some(String::from("wasm32-unknown-unknown")),
+            wasm: true,
+            ..Default::default()
         };
         let script = context.render();
 
@@ -86,4 +245,43 @@ mod test {
             insta::assert_snapshot!(script);
         });
     }
+
+    #[rstest]
+    fn test_audit(#[values(true, false)] is_bash: bool) {
+        let context = super::BuildScriptContext {
+            is_bash,
+            audit_lockfile: Some(String::from("my-prefix-dir/Cargo.lock")),
+            audit_deny: Some(String::from("warnings")),
+            ..Default::default()
+        };
+        let script = context.render();
+
+        let mut settings = insta::Settings::clone_current();
+        settings.set_snapshot_suffix(if is_bash { "bash" } else { "cmdexe" });
+        settings.bind(|| {
+            insta::assert_snapshot!(script);
+        });
+    }
+
+    #[rstest]
+    fn test_cargo_metadata(#[values(true, false)] is_bash: bool) {
+        let context = super::BuildScriptContext {
+            is_bash,
+            emit_cargo_metadata: true,
+            ..Default::default()
+        };
+        let script = context.render();
+
+        let metadata_path = if is_bash {
+            "$PREFIX/share/pixi-build/$PKG_NAME-metadata.json"
+        } else {
+            "%PREFIX%\\share\\pixi-build\\%PKG_NAME%-metadata.json"
+        };
+        assert!(
+            script.contains(&format!(
+                "cargo metadata --format-version 1 --no-deps --manifest-path my-prefix-dir/Cargo.toml > \"{metadata_path}\""
+            )),
+            "{script}"
+        );
+    }
 }
@@ -20,12 +20,81 @@ pub struct RustBackendConfig {
     /// Extra input globs to include in addition to the default ones
     #[serde(default)]
     pub extra_input_globs: Vec<String>,
+    /// Globs of input files to exclude from the default and extra input
+    /// globs, e.g. `target/**`. Uses the same glob syntax as the
+    /// input globs.
+    #[serde(default)]
+    pub exclude_input_globs: Vec<String>,
     /// Ignore the cargo manifest and depend only on the project model.
     #[serde(default)]
     pub ignore_cargo_manifest: Option<bool>,
     /// List of compilers to use (e.g., ["rust", "c", "cxx"])
     /// If not specified, a default will be used
     pub compilers: Option<Vec<String>>,
+    /// The S3 compatible endpoint sccache should use (`SCCACHE_ENDPOINT`)
+    pub sccache_endpoint: Option<String>,
+    /// The S3 bucket sccache should use (`SCCACHE_BUCKET`)
+    pub sccache_bucket: Option<String>,
+    /// The region of the S3 bucket (`SCCACHE_REGION`)
+    pub sccache_region: Option<String>,
+    /// Access the S3 bucket without credentials (`SCCACHE_NO_CREDENTIALS`)
+    pub sccache_no_credentials: Option<bool>,
+    /// Cargo features to enable (`--features`)
+    #[serde(default)]
+    pub features: Vec<String>,
+    /// Do not enable the default features of the package
+    /// (`--no-default-features`)
+    pub no_default_features: Option<bool>,
+    /// Run the tests with `cargo nextest` before installing the package
+    pub use_nextest: Option<bool>,
+    /// Overrides of the cargo release profile, e.g. `lto = "thin"`. Each
+    /// entry is passed as `CARGO_PROFILE_RELEASE_<KEY>`.
+    #[serde(default)]
+    pub cargo_profile_overrides: IndexMap<String, String>,
+    /// The cargo home directory (`CARGO_HOME`), e.g. to share a
+    /// pre-populated registry cache between builds
+    pub cargo_home: Option<PathBuf>,
+    /// Build without accessing the network (`CARGO_NET_OFFLINE`)
+    pub cargo_net_offline: Option<bool>,
+    /// Build the package for `wasm32-unknown-unknown` with `wasm-pack`
+    /// instead of installing it with `cargo install`
+    pub wasm: Option<bool>,
+    /// Check the dependencies for known vulnerabilities with `cargo audit`
+    /// before building the package
+    pub run_audit: Option<bool>,
+    /// The kind of `cargo audit` warnings that fail the build, next to
+    /// vulnerabilities. Defaults to `none`.
+    pub audit_deny_level: Option<AuditLevel>,
+    /// Write the `cargo metadata` of the package to
+    /// `share/pixi-build/<package>-metadata.json` as build provenance
+    pub emit_cargo_metadata: Option<bool>,
+}
+
+/// The kind of `cargo audit` warnings that are treated as errors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditLevel {
+    /// Every warning fails the build.
+    All,
+    /// Unmaintained crates fail the build.
+    Unmaintained,
+    /// Yanked crates fail the build.
+    Yanked,
+    /// Only vulnerabilities fail the build.
+    None,
+}
+
+impl AuditLevel {
+    /// Returns the value of the `--deny` argument of `cargo audit`, or `None`
+    /// if warnings should not fail the build.
+    pub fn deny_arg(&self) -> Option<&'static str> {
+        match self {
+            AuditLevel::All => Some("warnings"),
+            AuditLevel::Unmaintained => Some("unmaintained"),
+            AuditLevel::Yanked => Some("yanked"),
+            AuditLevel::None => None,
+        }
+    }
 }
 
 impl RustBackendConfig {
@@ -38,6 +107,60 @@ impl RustBackendConfig {
             ..Default::default()
         }
     }
+
+    /// Returns the `CARGO_PROFILE_RELEASE_*` environment variables of the
+    /// `cargo-profile-overrides`, e.g. `codegen-units` becomes
+    /// `CARGO_PROFILE_RELEASE_CODEGEN_UNITS`.
+    pub fn cargo_profile_env(&self) -> IndexMap<String, String> {
+        self.cargo_profile_overrides
+            .iter()
+            .map(|(key, value)| {
+                (
+                    format!(
+                        "CARGO_PROFILE_RELEASE_{}",
+                        key.to_uppercase().replace('-', "_")
+                    ),
+                    value.clone(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the `CARGO_HOME` and `CARGO_NET_OFFLINE` environment
+    /// variables of the `cargo-home` and `cargo-net-offline` configuration
+    /// values.
+    pub fn cargo_env(&self) -> IndexMap<String, String> {
+        let mut env = IndexMap::new();
+        if let Some(cargo_home) = &self.cargo_home {
+            env.insert("CARGO_HOME".to_string(), cargo_home.display().to_string());
+        }
+        if self.cargo_net_offline.is_some_and(|offline| offline) {
+            env.insert("CARGO_NET_OFFLINE".to_string(), "true".to_string());
+        }
+        env
+    }
+
+    /// Returns the sccache environment variables derived from the
+    /// `sccache-*` configuration values. Values that are not set, or are
+    /// empty, are omitted.
+    pub fn sccache_env(&self) -> IndexMap<String, String> {
+        [
+            ("SCCACHE_ENDPOINT", self.sccache_endpoint.clone()),
+            ("SCCACHE_BUCKET", self.sccache_bucket.clone()),
+            ("SCCACHE_REGION", self.sccache_region.clone()),
+            (
+                "SCCACHE_NO_CREDENTIALS",
+                self.sccache_no_credentials.map(|value| value.to_string()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| {
+            value
+                .filter(|value| !value.is_empty())
+                .map(|value| (key.to_string(), value))
+        })
+        .collect()
+    }
 }
 
 impl BackendConfig for RustBackendConfig {
@@ -51,6 +174,19 @@ impl BackendConfig for RustBackendConfig {
     /// - env: Platform env vars override base, others merge
     /// - debug_dir: Not allowed to have target specific value
     /// - extra_input_globs: Platform-specific completely replaces base
+    /// - exclude_input_globs: Platform-specific completely replaces base
+    /// - sccache_*: Platform-specific takes precedence over base
+    /// - features: Platform-specific completely replaces base
+    /// - no_default_features: Platform-specific takes precedence over base
+    /// - use_nextest: Platform-specific takes precedence over base
+    /// - cargo_profile_overrides: Platform overrides override base, others
+    ///   merge
+    /// - cargo_home: Platform-specific takes precedence over base
+    /// - cargo_net_offline: Platform-specific takes precedence over base
+    /// - wasm: Platform-specific takes precedence over base
+    /// - run_audit: Platform-specific takes precedence over base
+    /// - audit_deny_level: Platform-specific takes precedence over base
+    /// - emit_cargo_metadata: Platform-specific takes precedence over base
     fn merge_with_target_config(&self, target_config: &Self) -> miette::Result<Self> {
         if target_config.debug_dir.is_some() {
             miette::bail!("`debug_dir` cannot have a target specific value");
@@ -73,6 +209,11 @@ impl BackendConfig for RustBackendConfig {
             } else {
                 target_config.extra_input_globs.clone()
             },
+            exclude_input_globs: if target_config.exclude_input_globs.is_empty() {
+                self.exclude_input_globs.clone()
+            } else {
+                target_config.exclude_input_globs.clone()
+            },
             ignore_cargo_manifest: target_config
                 .ignore_cargo_manifest
                 .or(self.ignore_cargo_manifest),
@@ -80,13 +221,53 @@ impl BackendConfig for RustBackendConfig {
                 .compilers
                 .clone()
                 .or_else(|| self.compilers.clone()),
+            sccache_endpoint: target_config
+                .sccache_endpoint
+                .clone()
+                .or_else(|| self.sccache_endpoint.clone()),
+            sccache_bucket: target_config
+                .sccache_bucket
+                .clone()
+                .or_else(|| self.sccache_bucket.clone()),
+            sccache_region: target_config
+                .sccache_region
+                .clone()
+                .or_else(|| self.sccache_region.clone()),
+            sccache_no_credentials: target_config
+                .sccache_no_credentials
+                .or(self.sccache_no_credentials),
+            features: if target_config.features.is_empty() {
+                self.features.clone()
+            } else {
+                target_config.features.clone()
+            },
+            no_default_features: target_config
+                .no_default_features
+                .or(self.no_default_features),
+            use_nextest: target_config.use_nextest.or(self.use_nextest),
+            cargo_profile_overrides: {
+                let mut merged_overrides = self.cargo_profile_overrides.clone();
+                merged_overrides.extend(target_config.cargo_profile_overrides.clone());
+                merged_overrides
+            },
+            cargo_home: target_config
+                .cargo_home
+                .clone()
+                .or_else(|| self.cargo_home.clone()),
+            cargo_net_offline: target_config.cargo_net_offline.or(self.cargo_net_offline),
+            wasm: target_config.wasm.or(self.wasm),
+            run_audit: target_config.run_audit.or(self.run_audit),
+            audit_deny_level: target_config.audit_deny_level.or(self.audit_deny_level),
+            emit_cargo_metadata: target_config
+                .emit_cargo_metadata
+                .or(self.emit_cargo_metadata),
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::RustBackendConfig;
+    use super::{AuditLevel, RustBackendConfig};
     use pixi_build_backend::generated_recipe::BackendConfig;
     use serde_json::json;
     use std::path::PathBuf;
@@ -125,8 +306,23 @@ mod tests {
             env: base_env,
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             ignore_cargo_manifest: None,
             compilers: Some(vec!["rust".to_string()]),
+            sccache_endpoint: None,
+            sccache_bucket: None,
+            sccache_region: None,
+            sccache_no_credentials: None,
+            features: vec![],
+            no_default_features: None,
+            use_nextest: None,
+            cargo_profile_overrides: indexmap::IndexMap::new(),
+            cargo_home: None,
+            cargo_net_offline: None,
+            wasm: None,
+            run_audit: None,
+            audit_deny_level: None,
+            emit_cargo_metadata: None,
         };
 
         let mut target_env = indexmap::IndexMap::new();
@@ -138,8 +334,23 @@ mod tests {
             env: target_env,
             debug_dir: None,
             extra_input_globs: vec!["*.target".to_string()],
+            exclude_input_globs: vec![],
             ignore_cargo_manifest: Some(true),
             compilers: Some(vec!["c".to_string(), "rust".to_string()]),
+            sccache_endpoint: None,
+            sccache_bucket: None,
+            sccache_region: None,
+            sccache_no_credentials: None,
+            features: vec![],
+            no_default_features: None,
+            use_nextest: None,
+            cargo_profile_overrides: indexmap::IndexMap::new(),
+            cargo_home: None,
+            cargo_net_offline: None,
+            wasm: None,
+            run_audit: None,
+            audit_deny_level: None,
+            emit_cargo_metadata: None,
         };
 
         let merged = base_config
@@ -183,8 +394,23 @@ mod tests {
             env: base_env,
             debug_dir: Some(PathBuf::from("/base/debug")),
             extra_input_globs: vec!["*.base".to_string()],
+            exclude_input_globs: vec![],
             ignore_cargo_manifest: None,
             compilers: Some(vec!["rust".to_string()]),
+            sccache_endpoint: None,
+            sccache_bucket: None,
+            sccache_region: None,
+            sccache_no_credentials: None,
+            features: vec![],
+            no_default_features: None,
+            use_nextest: None,
+            cargo_profile_overrides: indexmap::IndexMap::new(),
+            cargo_home: None,
+            cargo_net_offline: None,
+            wasm: None,
+            run_audit: None,
+            audit_deny_level: None,
+            emit_cargo_metadata: None,
         };
 
         let empty_target_config = RustBackendConfig::default();
@@ -218,4 +444,50 @@ mod tests {
         let error_msg = result.unwrap_err().to_string();
         assert!(error_msg.contains("`debug_dir` cannot have a target specific value"));
     }
+
+    #[test]
+    fn test_sccache_env_from_config() {
+        let config = RustBackendConfig {
+            sccache_endpoint: Some("https://s3.example.com".to_string()),
+            sccache_bucket: Some("my-bucket".to_string()),
+            sccache_region: Some("eu-west-1".to_string()),
+            sccache_no_credentials: Some(true),
+            ..Default::default()
+        };
+
+        let env = config.sccache_env();
+        assert_eq!(
+            env.get("SCCACHE_ENDPOINT"),
+            Some(&"https://s3.example.com".to_string())
+        );
+        assert_eq!(env.get("SCCACHE_BUCKET"), Some(&"my-bucket".to_string()));
+        assert_eq!(env.get("SCCACHE_REGION"), Some(&"eu-west-1".to_string()));
+        assert_eq!(env.get("SCCACHE_NO_CREDENTIALS"), Some(&"true".to_string()));
+    }
+
+    #[test]
+    fn test_sccache_env_absent_fields() {
+        assert!(RustBackendConfig::default().sccache_env().is_empty());
+
+        let config = RustBackendConfig {
+            sccache_bucket: Some(String::new()),
+            ..Default::default()
+        };
+        assert!(config.sccache_env().is_empty());
+    }
+
+    #[test]
+    fn test_audit_deny_level() {
+        let config: RustBackendConfig = serde_json::from_value(json!({
+            "run-audit": true,
+            "audit-deny-level": "unmaintained",
+        }))
+        .unwrap();
+        assert_eq!(config.run_audit, Some(true));
+        assert_eq!(config.audit_deny_level, Some(AuditLevel::Unmaintained));
+
+        assert_eq!(AuditLevel::All.deny_arg(), Some("warnings"));
+        assert_eq!(AuditLevel::Yanked.deny_arg(), Some("yanked"));
+        assert_eq!(AuditLevel::None.deny_arg(), None);
+    }
 }
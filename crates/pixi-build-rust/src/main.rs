@@ -1,6 +1,9 @@
 mod build_script;
 mod config;
 mod metadata;
+mod target;
+mod toolchain;
+mod workspace;
 
 use build_script::BuildScriptContext;
 use config::RustBackendConfig;
@@ -18,7 +21,7 @@ use pixi_build_types::ProjectModelV1;
 use rattler_conda_types::{ChannelUrl, Platform};
 use recipe_stage0::{
     matchspec::PackageDependency,
-    recipe::{Item, Script},
+    recipe::{Item, NoArchKind, Script},
 };
 use std::collections::HashSet;
 use std::{
@@ -26,6 +29,24 @@ use std::{
     path::{Path, PathBuf},
     sync::Arc,
 };
+use toolchain::{RustToolchain, TOOLCHAIN_FILES, toolchain_file_glob};
+
+/// The configuration files of cargo-nextest.
+const NEXTEST_CONFIG_FILES: [&str; 2] = ["nextest.toml", ".config/nextest.toml"];
+
+/// The configuration file of cargo-audit.
+const AUDIT_CONFIG_FILE: &str = ".cargo/audit.toml";
+
+/// The rust target triple of `wasm` packages.
+const WASM_TARGET_TRIPLE: &str = "wasm32-unknown-unknown";
+
+/// The tools required to build a `wasm` package, next to the standard library
+/// of the wasm target.
+const WASM_BUILD_TOOLS: [&str; 3] = [
+    "wasm-pack",
+    "wasm-bindgen-cli",
+    "rust-std-wasm32-unknown-unknown",
+];
 
 #[derive(Default, Clone)]
 pub struct RustGenerator {}
@@ -99,6 +120,34 @@ impl GenerateRecipe for RustGenerator {
             variants,
         );
 
+        // Pin the rust toolchain to the channel of a `rust-toolchain.toml`,
+        // unless the user already specified a rust build dependency.
+        let toolchain = RustToolchain::from_manifest_root(&manifest_root)?;
+        let has_rust_dependency = model_dependencies
+            .build
+            .contains_key(&pixi_build_types::SourcePackageName::from("rust"));
+        let mut toolchain_packages = Vec::new();
+        if let Some(toolchain) = toolchain.as_ref().filter(|_| !has_rust_dependency) {
+            let version_spec = toolchain.version_spec();
+            let packages = std::iter::once("rust".to_string()).chain(
+                toolchain
+                    .targets
+                    .iter()
+                    .map(|target| format!("rust-std-{target}")),
+            );
+            for package in packages {
+                if toolchain_packages.contains(&package) {
+                    continue;
+                }
+                let spec = match &version_spec {
+                    Some(version_spec) => format!("{package} {version_spec}"),
+                    None => package.clone(),
+                };
+                toolchain_packages.push(package);
+                requirements.build.push(spec.parse().into_diagnostic()?);
+            }
+        }
+
         // Check if openssl is in the host dependencies
         let has_openssl = model_dependencies
             .host
@@ -106,7 +155,13 @@ impl GenerateRecipe for RustGenerator {
 
         let mut has_sccache = false;
 
-        let config_env = config.env.clone();
+        // Explicitly configured environment variables take precedence over the
+        // ones derived from the `sccache-*`, `cargo-profile-overrides`,
+        // `cargo-home` and `cargo-net-offline` configuration values.
+        let mut config_env = config.sccache_env();
+        config_env.extend(config.cargo_profile_env());
+        config_env.extend(config.cargo_env());
+        config_env.extend(config.env.clone());
 
         let system_env_vars = std::env::vars().collect::<HashMap<String, String>>();
 
@@ -156,12 +211,100 @@ impl GenerateRecipe for RustGenerator {
             has_sccache = true;
         }
 
+        // The tests are run with cargo-nextest, add it unless it is already
+        // part of the build requirements.
+        let use_nextest = config.use_nextest.unwrap_or(false);
+        if use_nextest {
+            let nextest_dep: Item<PackageDependency> = "cargo-nextest".parse().into_diagnostic()?;
+            if !requirements.build.contains(&nextest_dep)
+                && !model_dependencies
+                    .build
+                    .contains_key(&pixi_build_types::SourcePackageName::from("cargo-nextest"))
+            {
+                requirements.build.push(nextest_dep);
+            }
+        }
+
+        // The dependencies are checked with cargo-audit, add it unless it is
+        // already part of the build requirements.
+        let run_audit = config.run_audit.unwrap_or(false);
+        if run_audit {
+            let audit_dep: Item<PackageDependency> = "cargo-audit".parse().into_diagnostic()?;
+            if !requirements.build.contains(&audit_dep)
+                && !model_dependencies
+                    .build
+                    .contains_key(&pixi_build_types::SourcePackageName::from("cargo-audit"))
+            {
+                requirements.build.push(audit_dep);
+            }
+        }
+
+        // A wasm package is built with wasm-pack, which needs wasm-bindgen and
+        // the standard library of the wasm target. The standard library may
+        // already be pinned by the toolchain file.
+        let wasm = config.wasm.unwrap_or(false);
+        if wasm {
+            for tool in WASM_BUILD_TOOLS {
+                let tool_dep: Item<PackageDependency> = tool.parse().into_diagnostic()?;
+                if !toolchain_packages.iter().any(|package| package == tool)
+                    && !requirements.build.contains(&tool_dep)
+                    && !model_dependencies
+                        .build
+                        .contains_key(&pixi_build_types::SourcePackageName::from(tool))
+                {
+                    requirements.build.push(tool_dep);
+                }
+            }
+        }
+
+        // When cross-compiling, cargo has to build for the host platform instead
+        // of the platform that runs the build. A wasm package is always built
+        // for the wasm target.
+        let target_triple = if wasm {
+            Some(WASM_TARGET_TRIPLE.to_string())
+        } else if host_platform != Platform::current() {
+            target::platform_to_rust_triple(host_platform)
+        } else {
+            None
+        };
+
+        // If the manifest root is a cargo workspace, only build the member that
+        // matches the package instead of the workspace root.
+        let source_dir = model
+            .name
+            .as_deref()
+            .and_then(|name| workspace::detect_workspace_member(&manifest_root, name))
+            .unwrap_or_else(|| manifest_root.clone());
+
+        // The lock file of a workspace member is next to the workspace root,
+        // which can be above the package. Without a workspace the lock file is
+        // next to the package.
+        let audit_lockfile = run_audit.then(|| {
+            workspace::find_workspace_root(&source_dir)
+                .unwrap_or(&source_dir)
+                .join("Cargo.lock")
+                .display()
+                .to_string()
+        });
+
         let build_script = BuildScriptContext {
-            source_dir: manifest_root.display().to_string(),
+            source_dir: source_dir.display().to_string(),
             extra_args: config.extra_args.clone(),
+            features: config.features.clone(),
+            no_default_features: config.no_default_features.unwrap_or(false),
             has_openssl,
             has_sccache,
             is_bash: !Platform::current().is_windows(),
+            target_triple,
+            rustup_toolchain: toolchain.and_then(|toolchain| toolchain.channel),
+            use_nextest,
+            wasm,
+            audit_lockfile,
+            audit_deny: config
+                .audit_deny_level
+                .and_then(|level| level.deny_arg())
+                .map(String::from),
+            emit_cargo_metadata: config.emit_cargo_metadata.unwrap_or(false),
         }
         .render();
 
@@ -171,10 +314,23 @@ impl GenerateRecipe for RustGenerator {
             secrets: sccache_secrets,
         };
 
+        // The wasm output does not depend on the platform it is installed on.
+        if wasm {
+            generated_recipe.recipe.build.noarch = Some(NoArchKind::Generic);
+        }
+
         // Add the input globs from the Cargo metadata provider
         generated_recipe
             .metadata_input_globs
             .extend(cargo_metadata.input_globs());
+        // A toolchain file added next to the package takes precedence, the one
+        // of the workspace can be above the package.
+        generated_recipe
+            .metadata_input_globs
+            .extend(TOOLCHAIN_FILES.map(String::from));
+        generated_recipe
+            .metadata_input_globs
+            .extend(toolchain_file_glob(&manifest_root));
 
         Ok(generated_recipe)
     }
@@ -183,7 +339,7 @@ impl GenerateRecipe for RustGenerator {
     fn extract_input_globs_from_build(
         &self,
         config: &Self::Config,
-        _workdir: impl AsRef<Path>,
+        manifest_root: impl AsRef<Path>,
         _editable: bool,
     ) -> miette::Result<BTreeSet<String>> {
         Ok([
@@ -193,10 +349,38 @@ impl GenerateRecipe for RustGenerator {
             "Cargo.lock",
             // Build scripts
             "build.rs",
+            // Toolchain files
+            "rust-toolchain.toml",
+            "rust-toolchain",
         ]
         .iter()
         .map(|s| s.to_string())
+        .chain(
+            // The nextest configuration files
+            NEXTEST_CONFIG_FILES
+                .iter()
+                .filter(|_| config.use_nextest.unwrap_or(false))
+                .map(|s| s.to_string()),
+        )
+        .chain(
+            // The toolchain file of the workspace, which can be above the
+            // package
+            toolchain_file_glob(manifest_root.as_ref()),
+        )
+        .chain(
+            // The cargo-audit configuration, e.g. to ignore advisories
+            config
+                .run_audit
+                .is_some_and(|run_audit| run_audit)
+                .then(|| AUDIT_CONFIG_FILE.to_string()),
+        )
         .chain(config.extra_input_globs.clone())
+        .chain(
+            config
+                .exclude_input_globs
+                .iter()
+                .map(|glob| format!("!{glob}")),
+        )
         .collect())
     }
 
@@ -397,6 +581,73 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_cargo_profile_overrides() {
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_fixture!({
+                    "name": "foobar",
+                    "version": "0.1.0",
+                }),
+                &RustBackendConfig {
+                    cargo_profile_overrides: IndexMap::from([
+                        ("lto".to_string(), "thin".to_string()),
+                        ("codegen-units".to_string(), "1".to_string()),
+                        ("strip".to_string(), "symbols".to_string()),
+                        ("opt-level".to_string(), "s".to_string()),
+                    ]),
+                    env: IndexMap::from([(
+                        "CARGO_PROFILE_RELEASE_STRIP".to_string(),
+                        "debuginfo".to_string(),
+                    )]),
+                    ignore_cargo_manifest: Some(true),
+                    ..Default::default()
+                },
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        // Explicit environment variables take precedence over the overrides.
+        insta::assert_debug_snapshot!(generated_recipe.recipe.build.script.env);
+    }
+
+    #[test]
+    fn test_cargo_home_and_offline() {
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_fixture!({
+                    "name": "foobar",
+                    "version": "0.1.0",
+                }),
+                &RustBackendConfig {
+                    cargo_home: Some(PathBuf::from("/opt/cargo-cache")),
+                    cargo_net_offline: Some(true),
+                    ignore_cargo_manifest: Some(true),
+                    ..Default::default()
+                },
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let env = &generated_recipe.recipe.build.script.env;
+        assert_eq!(
+            env.get("CARGO_HOME").map(String::as_str),
+            Some("/opt/cargo-cache")
+        );
+        assert_eq!(
+            env.get("CARGO_NET_OFFLINE").map(String::as_str),
+            Some("true")
+        );
+    }
+
     #[test]
     fn test_sccache_is_enabled() {
         let project_model = project_fixture!({
@@ -444,6 +695,106 @@ mod tests {
         ".build.script.content" => "[ ... script ... ]",
         });
     }
+
+    #[test]
+    fn test_sccache_config_sets_script_env() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+            "targets": {
+                "default_target": {
+                    "run_dependencies": {
+                        "boltons": "*"
+                    }
+                },
+            }
+        });
+
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &RustBackendConfig {
+                    sccache_endpoint: Some("https://s3.example.com".to_string()),
+                    sccache_bucket: Some("my-bucket".to_string()),
+                    ignore_cargo_manifest: Some(true),
+                    ..Default::default()
+                },
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let env = &generated_recipe.recipe.build.script.env;
+        assert_eq!(
+            env.get("SCCACHE_ENDPOINT"),
+            Some(&"https://s3.example.com".to_string())
+        );
+        assert_eq!(env.get("SCCACHE_BUCKET"), Some(&"my-bucket".to_string()));
+        assert!(!env.contains_key("SCCACHE_REGION"));
+        assert!(!env.contains_key("SCCACHE_NO_CREDENTIALS"));
+
+        // Configuring the bucket enables sccache
+        assert!(
+            generated_recipe
+                .recipe
+                .requirements
+                .build
+                .iter()
+                .any(|item| item.to_string() == "sccache"),
+            "sccache should be in the build requirements"
+        );
+    }
+
+    #[test]
+    fn test_workspace_member_is_built() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = temp_dir.path();
+        fs_err::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        for name in ["foobar", "other"] {
+            fs_err::create_dir_all(root.join("crates").join(name)).unwrap();
+            fs_err::write(
+                root.join("crates").join(name).join("Cargo.toml"),
+                format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n"),
+            )
+            .unwrap();
+        }
+
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &RustBackendConfig::default_with_ignore_cargo_manifest(),
+                root.to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let member_path = root.join("crates").join("foobar");
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains(&format!("--path {}", member_path.display())),
+            "the workspace member should be installed"
+        );
+    }
+
     #[test]
     fn test_with_cargo_manifest() {
         let project_model = project_fixture!({});
@@ -889,4 +1240,308 @@ mod tests {
             "Recipe should contain conditional build dependency for gcc with unix condition"
         );
     }
+
+    #[test]
+    fn test_rust_toolchain_file_pins_rust() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("rust-toolchain.toml"),
+            "[toolchain]\nchannel = \"1.78.0\"\ntargets = [\"wasm32-unknown-unknown\"]\n",
+        )
+        .unwrap();
+
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &RustBackendConfig::default_with_ignore_cargo_manifest(),
+                temp_dir.path().to_path_buf(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build_requirements = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert!(build_requirements.contains(&"rust ==1.78.0".to_string()));
+        assert!(
+            build_requirements.contains(&"rust-std-wasm32-unknown-unknown ==1.78.0".to_string())
+        );
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("RUSTUP_TOOLCHAIN=1.78.0")
+        );
+        assert!(
+            generated_recipe
+                .metadata_input_globs
+                .contains("rust-toolchain.toml")
+        );
+    }
+
+    #[test]
+    fn test_wasm_with_toolchain_file_adds_rust_std_once() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        fs_err::write(
+            temp_dir.path().join("rust-toolchain.toml"),
+            "[toolchain]\nchannel = \"1.78.0\"\ntargets = [\"wasm32-unknown-unknown\"]\n",
+        )
+        .unwrap();
+        let member_dir = temp_dir.path().join("crates").join("foobar");
+        fs_err::create_dir_all(&member_dir).unwrap();
+
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &RustBackendConfig {
+                    wasm: Some(true),
+                    ignore_cargo_manifest: Some(true),
+                    ..Default::default()
+                },
+                member_dir.clone(),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let rust_std_requirements = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .filter(|item| item.starts_with("rust-std-wasm32-unknown-unknown"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rust_std_requirements,
+            vec!["rust-std-wasm32-unknown-unknown ==1.78.0"]
+        );
+
+        // The toolchain file of the workspace is an input of the member.
+        assert!(
+            generated_recipe
+                .metadata_input_globs
+                .contains("../../rust-toolchain.toml")
+        );
+        let input_globs = RustGenerator::default()
+            .extract_input_globs_from_build(&RustBackendConfig::default(), &member_dir, false)
+            .unwrap();
+        assert!(input_globs.contains("../../rust-toolchain.toml"));
+    }
+
+    #[test]
+    fn test_nextest_is_added_once() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &RustBackendConfig {
+                    use_nextest: Some(true),
+                    sccache_bucket: Some("my-bucket".to_string()),
+                    ignore_cargo_manifest: Some(true),
+                    ..Default::default()
+                },
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build_requirements = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            build_requirements
+                .iter()
+                .filter(|requirement| *requirement == "cargo-nextest")
+                .count(),
+            1,
+            "cargo-nextest should be in the build requirements exactly once: {build_requirements:?}"
+        );
+        assert!(build_requirements.contains(&"sccache".to_string()));
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("cargo nextest run --release")
+        );
+
+        let input_globs = RustGenerator::default()
+            .extract_input_globs_from_build(
+                &RustBackendConfig {
+                    use_nextest: Some(true),
+                    ..Default::default()
+                },
+                PathBuf::new(),
+                false,
+            )
+            .unwrap();
+        assert!(input_globs.contains("nextest.toml"));
+        assert!(input_globs.contains(".config/nextest.toml"));
+    }
+
+    #[test]
+    fn test_audit_checks_workspace_lockfile() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let manifest_root = temp_dir.path().join("foobar");
+        fs_err::create_dir_all(&manifest_root).unwrap();
+        fs_err::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"foobar\"]\n",
+        )
+        .unwrap();
+        fs_err::write(temp_dir.path().join("Cargo.lock"), "version = 4\n").unwrap();
+
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generate = || {
+            RustGenerator::default()
+                .generate_recipe(
+                    &project_model,
+                    &RustBackendConfig {
+                        run_audit: Some(true),
+                        audit_deny_level: Some(config::AuditLevel::Yanked),
+                        ignore_cargo_manifest: Some(true),
+                        ..Default::default()
+                    },
+                    manifest_root.clone(),
+                    Platform::Linux64,
+                    None,
+                    &HashSet::new(),
+                    vec![],
+                )
+                .expect("Failed to generate recipe")
+        };
+
+        let generated_recipe = generate();
+        let build_requirements = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        assert!(build_requirements.contains(&"cargo-audit".to_string()));
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains(&format!(
+                    "cargo audit --file {} --deny yanked",
+                    temp_dir.path().join("Cargo.lock").display()
+                ))
+        );
+
+        // Outside of a workspace, a lock file above the package is not used.
+        fs_err::remove_file(temp_dir.path().join("Cargo.toml")).unwrap();
+        assert!(generate().recipe.build.script.content.contains(&format!(
+            "cargo audit --file {} --deny yanked",
+            manifest_root.join("Cargo.lock").display()
+        )));
+
+        let input_globs = RustGenerator::default()
+            .extract_input_globs_from_build(
+                &RustBackendConfig {
+                    run_audit: Some(true),
+                    ..Default::default()
+                },
+                PathBuf::new(),
+                false,
+            )
+            .unwrap();
+        assert!(input_globs.contains(".cargo/audit.toml"));
+    }
+
+    #[test]
+    fn test_wasm_requirements() {
+        let project_model = project_fixture!({
+            "name": "foobar",
+            "version": "0.1.0",
+        });
+
+        let generated_recipe = RustGenerator::default()
+            .generate_recipe(
+                &project_model,
+                &RustBackendConfig {
+                    wasm: Some(true),
+                    ignore_cargo_manifest: Some(true),
+                    ..Default::default()
+                },
+                PathBuf::from("."),
+                Platform::Linux64,
+                None,
+                &HashSet::new(),
+                vec![],
+            )
+            .expect("Failed to generate recipe");
+
+        let build_requirements = generated_recipe
+            .recipe
+            .requirements
+            .build
+            .iter()
+            .map(|item| item.to_string())
+            .collect::<Vec<_>>();
+        for tool in WASM_BUILD_TOOLS {
+            assert!(
+                build_requirements.contains(&tool.to_string()),
+                "{tool} should be in the build requirements: {build_requirements:?}"
+            );
+        }
+        assert!(matches!(
+            generated_recipe.recipe.build.noarch,
+            Some(NoArchKind::Generic)
+        ));
+        assert!(
+            generated_recipe
+                .recipe
+                .build
+                .script
+                .content
+                .contains("wasm-pack build --release --target bundler")
+        );
+    }
 }
@@ -0,0 +1,45 @@
+use rattler_conda_types::Platform;
+
+/// Returns the rust target triple that corresponds to the given conda
+/// platform, or `None` if there is no such target.
+pub fn platform_to_rust_triple(platform: Platform) -> Option<String> {
+    let triple = match platform {
+        Platform::Linux32 => "i686-unknown-linux-gnu",
+        Platform::Linux64 => "x86_64-unknown-linux-gnu",
+        Platform::LinuxAarch64 => "aarch64-unknown-linux-gnu",
+        Platform::LinuxArmV7l => "armv7-unknown-linux-gnueabihf",
+        Platform::LinuxPpc64le => "powerpc64le-unknown-linux-gnu",
+        Platform::LinuxS390X => "s390x-unknown-linux-gnu",
+        Platform::Osx64 => "x86_64-apple-darwin",
+        Platform::OsxArm64 => "aarch64-apple-darwin",
+        Platform::Win32 => "i686-pc-windows-msvc",
+        Platform::Win64 => "x86_64-pc-windows-msvc",
+        Platform::WinArm64 => "aarch64-pc-windows-msvc",
+        Platform::EmscriptenWasm32 => "wasm32-unknown-emscripten",
+        Platform::WasiWasm32 => "wasm32-wasip1",
+        _ => return None,
+    };
+    Some(triple.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_platform_to_rust_triple() {
+        assert_eq!(
+            platform_to_rust_triple(Platform::LinuxAarch64).as_deref(),
+            Some("aarch64-unknown-linux-gnu")
+        );
+        assert_eq!(
+            platform_to_rust_triple(Platform::OsxArm64).as_deref(),
+            Some("aarch64-apple-darwin")
+        );
+        assert_eq!(
+            platform_to_rust_triple(Platform::Win64).as_deref(),
+            Some("x86_64-pc-windows-msvc")
+        );
+        assert_eq!(platform_to_rust_triple(Platform::NoArch), None);
+    }
+}
@@ -0,0 +1,224 @@
+use std::path::{Path, PathBuf};
+
+use miette::{Context, IntoDiagnostic};
+use serde::Deserialize;
+
+/// The files rustup reads the toolchain of a project from, in order of
+/// precedence.
+pub const TOOLCHAIN_FILES: [&str; 2] = ["rust-toolchain.toml", "rust-toolchain"];
+
+#[derive(Debug, Deserialize)]
+struct ToolchainFile {
+    toolchain: RustToolchain,
+}
+
+/// The `[toolchain]` section of a `rust-toolchain.toml` file.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub struct RustToolchain {
+    /// The toolchain channel, e.g. `1.78.0`, `stable` or `nightly-2024-01-01`.
+    pub channel: Option<String>,
+    /// Additional targets the standard library is required for.
+    #[serde(default)]
+    pub targets: Vec<String>,
+}
+
+/// Returns the toolchain file that applies to `manifest_root`, or `None` if
+/// the project does not pin a toolchain.
+///
+/// Like rustup, the toolchain file is looked up in `manifest_root` and its
+/// parent directories, up to the root of the cargo workspace. Without a
+/// workspace only `manifest_root` is searched.
+pub fn find_toolchain_file(manifest_root: &Path) -> Option<PathBuf> {
+    let search_dirs = match crate::workspace::find_workspace_root(manifest_root) {
+        Some(workspace_root) => manifest_root
+            .ancestors()
+            .take_while(|dir| dir.starts_with(workspace_root))
+            .collect::<Vec<_>>(),
+        None => vec![manifest_root],
+    };
+    search_dirs.into_iter().find_map(|dir| {
+        TOOLCHAIN_FILES
+            .iter()
+            .map(|file_name| dir.join(file_name))
+            .find(|path| path.is_file())
+    })
+}
+
+/// Returns the path of the toolchain file that applies to `manifest_root`,
+/// relative to `manifest_root`, to use as an input glob, e.g.
+/// `../../rust-toolchain.toml` for a member of a cargo workspace.
+pub fn toolchain_file_glob(manifest_root: &Path) -> Option<String> {
+    let path = find_toolchain_file(manifest_root)?;
+    let relative_path = pathdiff::diff_paths(path, manifest_root)?;
+    Some(relative_path.to_string_lossy().replace('\\', "/"))
+}
+
+impl RustToolchain {
+    /// Reads the toolchain file that applies to `manifest_root`, returns
+    /// `None` if the project does not pin a toolchain.
+    ///
+    /// See [`find_toolchain_file`] for where the toolchain file is looked up.
+    pub fn from_manifest_root(manifest_root: &Path) -> miette::Result<Option<Self>> {
+        find_toolchain_file(manifest_root)
+            .map(|path| Self::from_file(&path))
+            .transpose()
+    }
+
+    /// Reads the toolchain file at `path`.
+    ///
+    /// The legacy `rust-toolchain` file may contain either TOML or only the
+    /// name of the channel.
+    fn from_file(path: &Path) -> miette::Result<Self> {
+        let contents = fs_err::read_to_string(path).into_diagnostic()?;
+        match toml::from_str::<ToolchainFile>(&contents) {
+            Ok(toolchain_file) => Ok(toolchain_file.toolchain),
+            Err(_)
+                if path
+                    .file_name()
+                    .is_some_and(|name| name == "rust-toolchain")
+                    && !contents.contains('[') =>
+            {
+                Ok(Self {
+                    channel: Some(contents.trim().to_string()),
+                    targets: Vec::new(),
+                })
+            }
+            Err(err) => Err(err)
+                .into_diagnostic()
+                .with_context(|| format!("failed to parse {}", path.display())),
+        }
+    }
+
+    /// Returns the version constraint of the conda `rust` package that
+    /// matches the channel, or `None` if the channel does not name a
+    /// version, e.g. `stable` or `nightly`.
+    pub fn version_spec(&self) -> Option<String> {
+        let channel = self.channel.as_deref()?;
+        let components = channel.split('.').collect::<Vec<_>>();
+        if components
+            .iter()
+            .any(|component| component.is_empty() || !component.chars().all(|c| c.is_ascii_digit()))
+        {
+            return None;
+        }
+        match components.len() {
+            2 => Some(format!("{channel}.*")),
+            3 => Some(format!("=={channel}")),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn toolchain(channel: &str) -> RustToolchain {
+        RustToolchain {
+            channel: Some(channel.to_string()),
+            targets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_toolchain_toml() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("rust-toolchain.toml"),
+            r#"[toolchain]
+channel = "1.78.0"
+components = ["rustfmt", "clippy"]
+targets = ["wasm32-unknown-unknown"]
+"#,
+        )
+        .unwrap();
+
+        let parsed = RustToolchain::from_manifest_root(temp_dir.path())
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed.channel.as_deref(), Some("1.78.0"));
+        assert_eq!(parsed.targets, vec!["wasm32-unknown-unknown"]);
+    }
+
+    #[test]
+    fn test_parse_legacy_toolchain_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("rust-toolchain"),
+            "nightly-2024-01-01\n",
+        )
+        .unwrap();
+
+        let parsed = RustToolchain::from_manifest_root(temp_dir.path())
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed, toolchain("nightly-2024-01-01"));
+
+        // Without a toolchain file there is nothing to pin.
+        let empty_dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            RustToolchain::from_manifest_root(empty_dir.path()).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_toolchain_file_in_workspace_root() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        fs_err::write(temp_dir.path().join("rust-toolchain"), "1.78.0\n").unwrap();
+        let member_dir = temp_dir.path().join("crates").join("foobar");
+        fs_err::create_dir_all(&member_dir).unwrap();
+
+        // A member without its own toolchain file uses the one of the workspace.
+        assert_eq!(
+            RustToolchain::from_manifest_root(&member_dir).unwrap(),
+            Some(toolchain("1.78.0"))
+        );
+
+        assert_eq!(
+            toolchain_file_glob(&member_dir).as_deref(),
+            Some("../../rust-toolchain")
+        );
+
+        // The toolchain file of the member takes precedence.
+        fs_err::write(member_dir.join("rust-toolchain"), "1.80.0\n").unwrap();
+        assert_eq!(
+            RustToolchain::from_manifest_root(&member_dir).unwrap(),
+            Some(toolchain("1.80.0"))
+        );
+        assert_eq!(
+            toolchain_file_glob(&member_dir).as_deref(),
+            Some("rust-toolchain")
+        );
+    }
+
+    #[test]
+    fn test_toolchain_file_outside_workspace_is_ignored() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(temp_dir.path().join("rust-toolchain"), "1.78.0\n").unwrap();
+        let member_dir = temp_dir.path().join("foobar");
+        fs_err::create_dir_all(&member_dir).unwrap();
+
+        assert_eq!(
+            RustToolchain::from_manifest_root(&member_dir).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_version_spec() {
+        assert_eq!(
+            toolchain("1.78.0").version_spec().as_deref(),
+            Some("==1.78.0")
+        );
+        assert_eq!(toolchain("1.78").version_spec().as_deref(), Some("1.78.*"));
+        assert_eq!(toolchain("stable").version_spec(), None);
+        assert_eq!(toolchain("nightly-2024-01-01").version_spec(), None);
+        assert_eq!(RustToolchain::default().version_spec(), None);
+    }
+}
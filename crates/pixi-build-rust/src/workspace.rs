@@ -0,0 +1,171 @@
+use std::path::{Path, PathBuf};
+
+use cargo_toml::Manifest;
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use walkdir::WalkDir;
+
+/// Directories that never contain workspace members.
+const IGNORED_DIRS: [&str; 3] = ["target", ".git", ".pixi"];
+
+/// Finds the member of the cargo workspace at `manifest_root` whose package
+/// is called `package_name`.
+///
+/// Returns the directory of the member, or `None` if `manifest_root` does not
+/// contain a workspace, the root manifest itself is the requested package, or
+/// no member with the given name exists.
+pub fn detect_workspace_member(manifest_root: &Path, package_name: &str) -> Option<PathBuf> {
+    let manifest = read_manifest(&manifest_root.join("Cargo.toml"))?;
+    let workspace = manifest.workspace?;
+
+    // The root package of a non-virtual workspace is built as before.
+    if manifest
+        .package
+        .is_some_and(|package| package.name == package_name)
+    {
+        return None;
+    }
+
+    let members = build_glob_set(&workspace.members)?;
+    let excluded = build_glob_set(&workspace.exclude)?;
+
+    WalkDir::new(manifest_root)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.file_type().is_dir()
+                && !IGNORED_DIRS.contains(&entry.file_name().to_string_lossy().as_ref())
+        })
+        .filter_map(Result::ok)
+        .find_map(|entry| {
+            let relative = entry.path().strip_prefix(manifest_root).ok()?;
+            if !members.is_match(relative) || excluded.is_match(relative) {
+                return None;
+            }
+
+            let member = read_manifest(&entry.path().join("Cargo.toml"))?;
+            member
+                .package
+                .is_some_and(|package| package.name == package_name)
+                .then(|| entry.path().to_path_buf())
+        })
+}
+
+/// Returns the root of the cargo workspace that contains `manifest_root`, i.e.
+/// the closest directory, starting at `manifest_root` itself, whose
+/// `Cargo.toml` has a `[workspace]` table.
+pub fn find_workspace_root(manifest_root: &Path) -> Option<&Path> {
+    manifest_root.ancestors().find(|dir| {
+        read_manifest(&dir.join("Cargo.toml")).is_some_and(|manifest| manifest.workspace.is_some())
+    })
+}
+
+/// Reads a `Cargo.toml` without resolving workspace inheritance.
+fn read_manifest(path: &Path) -> Option<Manifest> {
+    let contents = fs_err::read(path).ok()?;
+    Manifest::from_slice(&contents).ok()
+}
+
+fn build_glob_set(patterns: &[String]) -> Option<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern.trim_end_matches('/'))
+            .literal_separator(true)
+            .build()
+            .ok()?;
+        builder.add(glob);
+    }
+    builder.build().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_package(root: &Path, path: &str, name: &str) {
+        let dir = root.join(path);
+        fs_err::create_dir_all(&dir).unwrap();
+        fs_err::write(
+            dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\n"),
+        )
+        .unwrap();
+    }
+
+    fn workspace_fixture() -> tempfile::TempDir {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("Cargo.toml"),
+            r#"[workspace]
+members = ["crates/*", "tools/cli"]
+exclude = ["crates/excluded"]
+"#,
+        )
+        .unwrap();
+        write_package(temp_dir.path(), "crates/core", "my-core");
+        write_package(temp_dir.path(), "crates/utils", "my-utils");
+        write_package(temp_dir.path(), "crates/excluded", "my-excluded");
+        write_package(temp_dir.path(), "tools/cli", "my-cli");
+        write_package(temp_dir.path(), "tools/other", "my-other");
+        temp_dir
+    }
+
+    #[test]
+    fn test_detect_workspace_member() {
+        let workspace = workspace_fixture();
+        let root = workspace.path();
+
+        assert_eq!(
+            detect_workspace_member(root, "my-core"),
+            Some(root.join("crates/core"))
+        );
+        assert_eq!(
+            detect_workspace_member(root, "my-utils"),
+            Some(root.join("crates/utils"))
+        );
+        assert_eq!(
+            detect_workspace_member(root, "my-cli"),
+            Some(root.join("tools/cli"))
+        );
+    }
+
+    #[test]
+    fn test_detect_workspace_member_not_a_member() {
+        let workspace = workspace_fixture();
+        let root = workspace.path();
+
+        assert_eq!(detect_workspace_member(root, "my-excluded"), None);
+        assert_eq!(detect_workspace_member(root, "my-other"), None);
+        assert_eq!(detect_workspace_member(root, "unknown"), None);
+    }
+
+    #[test]
+    fn test_detect_workspace_member_root_package() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        fs_err::write(
+            temp_dir.path().join("Cargo.toml"),
+            r#"[package]
+name = "root"
+version = "0.1.0"
+
+[workspace]
+members = ["crates/*"]
+"#,
+        )
+        .unwrap();
+        write_package(temp_dir.path(), "crates/core", "my-core");
+
+        assert_eq!(detect_workspace_member(temp_dir.path(), "root"), None);
+        assert_eq!(
+            detect_workspace_member(temp_dir.path(), "my-core"),
+            Some(temp_dir.path().join("crates/core"))
+        );
+    }
+
+    #[test]
+    fn test_detect_workspace_member_without_workspace() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        write_package(temp_dir.path(), ".", "my-package");
+
+        assert_eq!(detect_workspace_member(temp_dir.path(), "my-package"), None);
+    }
+}
@@ -1,4 +1,3 @@
-import os
 from pathlib import Path
 from typing import Any, Optional, Dict, List
 import re
@@ -144,11 +143,7 @@ def get_build_input_globs(config: Any, workdir: Path, editable: bool) -> List[st
 
 
 def get_editable_setting(python_params: Any) -> bool:
-    """Get editable setting from environment or params."""
-    env_editable = os.environ.get("BUILD_EDITABLE_PYTHON", "").lower() == "true"
-    if env_editable:
-        return True
-
+    """Get editable setting from params."""
     if python_params and hasattr(python_params, "editable"):
         return bool(python_params.editable)
 